@@ -1,7 +1,12 @@
 //! 异步API
-use std::cell::RefCell;
+use std::{cell::RefCell, path::Path, time::Instant};
 
-use crate::{config::Config, constant::COMMON_URL, lang::Lang, model::CommonResult, util};
+use serde::de::DeserializeOwned;
+use tokio::fs;
+
+use crate::{
+    config::Config, constant::COMMON_URL, lang::Lang, model::CommonResult, response::Response, util,
+};
 
 /// 百度翻译客户端
 pub struct Client {
@@ -23,21 +28,195 @@ impl Client {
         self.config.borrow_mut().set_from(from);
         self.config.borrow_mut().set_to(to);
     }
+
+    /// 当前的源语言和目标语言
+    fn langs(&self) -> (Lang, Lang) {
+        let config = self.config.borrow();
+        (config.from, config.to)
+    }
+
+    /// 构建通用翻译表单，语言方向由参数指定
+    fn translate_form(
+        &self,
+        q: &str,
+        from: Lang,
+        to: Lang,
+    ) -> std::collections::HashMap<String, String> {
+        util::build_form(&self.config.borrow(), q, from, to)
+    }
+
+    /// 设置图片翻译请求参数，如图片贴合类型
+    #[cfg(feature = "image")]
+    pub fn image_request(&self, request: crate::image::ImageRequest) {
+        self.config.borrow_mut().image = request;
+    }
+
+    /// 设置文档翻译任务的轮询参数
+    #[cfg(feature = "doc")]
+    pub fn doc_poll(&self, options: crate::document::job::PollOptions) {
+        self.config.borrow_mut().doc_poll = options;
+    }
+
+    /// 发送请求并解析响应
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> anyhow::Result<Response<T>> {
+        let start = Instant::now();
+        let resp = request.send().await?;
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await?.to_vec();
+
+        Response::decode(status, headers, body, start.elapsed())
+    }
 }
 
 impl Client {
     /// 通用翻译
     pub async fn translate(&self, q: &str) -> anyhow::Result<CommonResult> {
-        let params = util::build_form(&self.config.borrow(), q);
+        Ok(self.translate_raw(q).await?.data)
+    }
 
-        let resp = self
-            .http_client
-            .post(COMMON_URL)
-            .form(&params)
-            .send()
-            .await?;
+    /// 通用翻译，返回原始响应信息
+    pub async fn translate_raw(&self, q: &str) -> anyhow::Result<Response<CommonResult>> {
+        let (from, to) = self.langs();
+        let params = self.translate_form(q, from, to);
+
+        self.send(self.http_client.post(COMMON_URL).form(&params))
+            .await
+    }
+
+    /// 使用客户端术语表进行通用翻译
+    ///
+    /// 原文中的术语先替换为占位符，翻译后还原为指定的译文，
+    /// 被百度改写的占位符记录在[`GlossaryResult::mangled`](crate::glossary::GlossaryResult::mangled)中
+    /// - q: 源文本
+    /// - glossary: 术语表
+    #[cfg(feature = "glossary")]
+    pub async fn translate_glossary(
+        &self,
+        q: &str,
+        glossary: &crate::glossary::Glossary,
+    ) -> anyhow::Result<crate::glossary::GlossaryResult> {
+        Ok(self.translate_glossary_raw(q, glossary).await?.data)
+    }
+
+    /// 使用客户端术语表进行通用翻译，返回原始响应信息
+    ///
+    /// 原始响应体中是占位符，解析后的返回结果中占位符已还原
+    /// - q: 源文本
+    /// - glossary: 术语表
+    #[cfg(feature = "glossary")]
+    pub async fn translate_glossary_raw(
+        &self,
+        q: &str,
+        glossary: &crate::glossary::Glossary,
+    ) -> anyhow::Result<Response<crate::glossary::GlossaryResult>> {
+        let (from, to) = self.langs();
+        let protected = glossary.protect(q, from, to);
+        let result = self.translate_raw(&protected.text).await?;
+
+        Ok(result.map(|result| protected.restore_result(result)))
+    }
+
+    /// 大模型文本翻译
+    /// - q: 源文本
+    /// - request: 大模型翻译请求参数
+    #[cfg(feature = "llm")]
+    pub async fn llm_translate(
+        &self,
+        q: &str,
+        request: &crate::llm::LlmRequest,
+    ) -> anyhow::Result<crate::llm::model::LlmResult> {
+        Ok(self.llm_translate_raw(q, request).await?.data)
+    }
+
+    /// 大模型文本翻译，返回原始响应信息
+    /// - q: 源文本
+    /// - request: 大模型翻译请求参数
+    #[cfg(feature = "llm")]
+    pub async fn llm_translate_raw(
+        &self,
+        q: &str,
+        request: &crate::llm::LlmRequest,
+    ) -> anyhow::Result<Response<crate::llm::model::LlmResult>> {
+        use crate::constant::LLM_URL;
+
+        let body = util::build_llm_body(&self.config.borrow(), q, request);
+
+        self.send(self.http_client.post(LLM_URL).json(&body)).await
+    }
+
+    /// 下载TTS音频，返回mp3数据
+    /// - url: `src_tts`或`dst_tts`链接
+    pub async fn tts(&self, url: &str) -> anyhow::Result<Vec<u8>> {
+        let resp = self.http_client.get(url).send().await?.error_for_status()?;
+
+        Ok(resp.bytes().await?.to_vec())
+    }
+
+    /// 下载TTS音频并保存到指定路径
+    /// - url: `src_tts`或`dst_tts`链接
+    /// - path: 保存路径
+    pub async fn save_tts(&self, url: &str, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let data = self.tts(url).await?;
+        fs::write(path, data).await?;
 
-        Ok(resp.json().await?)
+        Ok(())
+    }
+
+    /// 获取原文发音，设置了`tts_cache_dir`时优先读取缓存
+    ///
+    /// 翻译结果中没有原文tts链接时返回`Ok(None)`
+    pub async fn src_tts(&self, result: &CommonResult) -> anyhow::Result<Option<Vec<u8>>> {
+        match &result.src_tts {
+            Some(url) => {
+                let lang = result.from.as_deref().unwrap_or_default();
+                Ok(Some(self.cached_tts(url, &result.src_text(), lang).await?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 获取译文发音，设置了`tts_cache_dir`时优先读取缓存
+    ///
+    /// 翻译结果中没有译文tts链接时返回`Ok(None)`
+    pub async fn dst_tts(&self, result: &CommonResult) -> anyhow::Result<Option<Vec<u8>>> {
+        match &result.dst_tts {
+            Some(url) => {
+                let lang = result.to.as_deref().unwrap_or_default();
+                Ok(Some(self.cached_tts(url, &result.dst_text(), lang).await?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn cached_tts(&self, url: &str, text: &str, lang: &str) -> anyhow::Result<Vec<u8>> {
+        let cache_dir = self.config.borrow().tts_cache_dir.clone();
+        let Some(cache_dir) = cache_dir else {
+            return self.tts(url).await;
+        };
+
+        let path = util::tts_cache_path(&cache_dir, text, lang);
+        if let Ok(data) = fs::read(&path).await {
+            return Ok(data);
+        }
+
+        let data = self.tts(url).await?;
+        // 先写入临时文件再重命名，避免中断时留下不完整的缓存；
+        // 同一文本并发下载时各自使用不同的临时文件，重命名失败但缓存已存在时视为命中
+        let tmp = util::temp_path(&path);
+        fs::create_dir_all(&cache_dir).await?;
+        fs::write(&tmp, &data).await?;
+        if let Err(err) = fs::rename(&tmp, &path).await {
+            let _ = fs::remove_file(&tmp).await;
+            if fs::metadata(&path).await.is_err() {
+                return Err(err.into());
+            }
+        }
+
+        Ok(data)
     }
 
     /// 图片翻译
@@ -47,23 +226,249 @@ impl Client {
         name: &str,
         data: Vec<u8>,
     ) -> anyhow::Result<crate::image::model::ImageResult> {
-        let multipart_form = util::create_image_form(
-            name,
-            data,
-            &self.config.borrow(),
-            "APICUID",
-            "mac",
-            "3",
-            None,
-        );
+        Ok(self.image_translate_raw(name, data).await?.data)
+    }
+
+    /// 图片翻译，返回原始响应信息
+    #[cfg(feature = "image")]
+    pub async fn image_translate_raw(
+        &self,
+        name: &str,
+        data: Vec<u8>,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>> {
+        let (from, to) = self.langs();
+        self.image_translate_with_lang(name, data, from, to).await
+    }
+
+    /// 图片翻译，从文件中流式上传图片，文件名作为上传的文件名
+    /// - path: 图片路径
+    #[cfg(feature = "image")]
+    pub async fn image_translate_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<crate::image::model::ImageResult> {
+        Ok(self.image_translate_file_raw(path).await?.data)
+    }
+
+    /// 图片翻译，从文件中流式上传图片，返回原始响应信息
+    /// - path: 图片路径
+    #[cfg(feature = "image")]
+    pub async fn image_translate_file_raw(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>> {
+        let path = path.as_ref();
+        let name = crate::image::file_name(path)?;
+
+        self.image_translate_reader_raw(&name, fs::File::open(path).await?)
+            .await
+    }
+
+    /// 图片翻译，从reader中流式上传图片
+    ///
+    /// 签名需要图片的md5，所以会先读取一遍计算md5，再回到开头上传
+    /// - name: 文件名
+    /// - reader: 图片数据
+    #[cfg(feature = "image")]
+    pub async fn image_translate_reader<R>(
+        &self,
+        name: &str,
+        reader: R,
+    ) -> anyhow::Result<crate::image::model::ImageResult>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Send + Sync + Unpin + 'static,
+    {
+        Ok(self.image_translate_reader_raw(name, reader).await?.data)
+    }
+
+    /// 图片翻译，从reader中流式上传图片，返回原始响应信息
+    /// - name: 文件名
+    /// - reader: 图片数据
+    #[cfg(feature = "image")]
+    pub async fn image_translate_reader_raw<R>(
+        &self,
+        name: &str,
+        reader: R,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Send + Sync + Unpin + 'static,
+    {
+        // 预处理需要完整的图片数据
+        #[cfg(feature = "preprocess")]
+        if self.config.borrow().image.preprocess.is_some() {
+            use tokio::io::AsyncReadExt;
+
+            let mut reader = reader;
+            let mut data = Vec::new();
+            reader.read_to_end(&mut data).await?;
+            return self.image_translate_raw(name, data).await;
+        }
+
+        let (part, image_md5) = util::reader_part_aio(name, reader).await?;
+        let (from, to) = self.langs();
+        self.image_translate_part(part, &image_md5, from, to).await
+    }
+
+    #[cfg(feature = "image")]
+    async fn image_translate_part(
+        &self,
+        part: reqwest::multipart::Part,
+        image_md5: &str,
+        from: Lang,
+        to: Lang,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>> {
+        let multipart_form =
+            util::create_image_form(part, image_md5, from, to, &self.config.borrow());
+
+        self.send(
+            self.http_client
+                .post(crate::constant::IMAGE_URL)
+                .multipart(multipart_form),
+        )
+        .await
+    }
+
+    #[cfg(feature = "image")]
+    async fn image_translate_with_lang(
+        &self,
+        name: &str,
+        data: Vec<u8>,
+        from: Lang,
+        to: Lang,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>> {
+        use reqwest::multipart::Part;
+
+        #[cfg(feature = "preprocess")]
+        let (name, data, prepared) =
+            crate::image::prepare(&self.config.borrow().image, name, data)?;
+
+        let image_md5 = util::md5_hex(&data);
+        let part = Part::bytes(data).file_name(name.to_string());
         let resp = self
-            .http_client
-            .post(crate::constant::IMAGE_URL)
-            .multipart(multipart_form)
-            .send()
+            .image_translate_part(part, &image_md5, from, to)
             .await?;
 
-        Ok(resp.json().await?)
+        #[cfg(feature = "preprocess")]
+        let resp = {
+            let mut resp = resp;
+            if let Some(prepared) = prepared {
+                prepared.restore(&mut resp.data);
+            }
+            resp
+        };
+
+        Ok(resp)
+    }
+
+    /// 文字识别，调用图片翻译接口并只保留识别出的原文及其位置
+    ///
+    /// 目标语言为自动时使用一个与源语言不同的语种
+    /// - name: 文件名
+    /// - data: 图片数据
+    #[cfg(feature = "image")]
+    pub async fn image_ocr(
+        &self,
+        name: &str,
+        data: Vec<u8>,
+    ) -> anyhow::Result<crate::image::ocr::OcrResult> {
+        Ok(self.image_ocr_raw(name, data).await?.data)
+    }
+
+    /// 文字识别，返回原始响应信息
+    /// - name: 文件名
+    /// - data: 图片数据
+    #[cfg(feature = "image")]
+    pub async fn image_ocr_raw(
+        &self,
+        name: &str,
+        data: Vec<u8>,
+    ) -> anyhow::Result<Response<crate::image::ocr::OcrResult>> {
+        let (from, to) = self.langs();
+        let to = crate::image::ocr::target_lang(from, to);
+        let resp = self.image_translate_with_lang(name, data, from, to).await?;
+
+        Ok(resp.map(Into::into))
+    }
+
+    /// 批量翻译目录中的图片，结果保存在图片旁边，已有成功结果的图片会被跳过
+    ///
+    /// 单张图片失败不会中断整个批次，失败的图片记录在返回结果中
+    /// - dir: 图片目录
+    /// - options: 批量翻译参数
+    /// - progress: 每处理完一张图片调用一次
+    #[cfg(feature = "batch")]
+    pub async fn image_translate_dir(
+        &self,
+        dir: impl AsRef<Path>,
+        options: &crate::image::batch::BatchOptions,
+        mut progress: impl FnMut(&crate::image::batch::BatchProgress),
+    ) -> anyhow::Result<crate::image::batch::BatchReport> {
+        use futures_util::StreamExt;
+
+        use crate::image::batch::{self, BatchProgress, BatchReport, BatchStatus};
+
+        let files = batch::collect(dir.as_ref(), options)?;
+        let total = files.len();
+        let mut report = BatchReport::default();
+
+        let mut tasks = futures_util::stream::iter(files)
+            .map(|path| async move {
+                let status = if !options.overwrite && batch::is_completed(&path).await {
+                    BatchStatus::Skipped
+                } else {
+                    match self.image_translate_batch_item(&path, options).await {
+                        Ok(()) => BatchStatus::Done,
+                        Err(err) => BatchStatus::Failed(err),
+                    }
+                };
+                (path, status)
+            })
+            .buffer_unordered(options.concurrency.max(1));
+
+        let mut completed = 0;
+        while let Some((path, status)) = tasks.next().await {
+            completed += 1;
+            progress(&BatchProgress {
+                path: &path,
+                status: &status,
+                completed,
+                total,
+            });
+            report.record(path, status);
+        }
+
+        Ok(report)
+    }
+
+    #[cfg(feature = "batch")]
+    async fn image_translate_batch_item(
+        &self,
+        path: &Path,
+        options: &crate::image::batch::BatchOptions,
+    ) -> anyhow::Result<()> {
+        use crate::image::batch;
+
+        let result = self.image_translate_file(path).await?;
+        if !result.error_code.is_success() {
+            anyhow::bail!("{}: {}", result.error_code, result.error_msg);
+        }
+
+        if options.save_paste {
+            let paste = match &result.data {
+                Some(data) => data.paste_image()?,
+                None => None,
+            };
+            if let Some(paste) = paste {
+                fs::write(batch::paste_path(path), paste).await?;
+            }
+        }
+        fs::write(
+            batch::result_path(path),
+            serde_json::to_vec_pretty(&result)?,
+        )
+        .await?;
+
+        Ok(())
     }
 
     /// 垂直领域翻译
@@ -75,69 +480,840 @@ impl Client {
         q: &str,
         domain: crate::domain::Domain,
     ) -> anyhow::Result<crate::domain::model::DomainResult> {
+        Ok(self.domain_translate_raw(q, domain).await?.data)
+    }
+
+    /// 垂直领域翻译，返回原始响应信息
+    /// - q: 要翻译的文本
+    /// - domain: 选择垂直领域
+    #[cfg(feature = "domain")]
+    pub async fn domain_translate_raw(
+        &self,
+        q: &str,
+        domain: crate::domain::Domain,
+    ) -> anyhow::Result<Response<crate::domain::model::DomainResult>> {
         use crate::constant::DOMAIN_URL;
 
-        let params = util::build_domain_form(&self.config.borrow(), q, domain);
+        let (from, to) = self.langs();
+        let params = self.domain_form(q, domain, from, to);
 
-        let resp = self
-            .http_client
-            .post(DOMAIN_URL)
-            .form(&params)
-            .send()
-            .await?;
+        self.send(self.http_client.post(DOMAIN_URL).form(&params))
+            .await
+    }
+
+    /// 垂直领域翻译，领域不支持当前语言方向时回退到通用翻译
+    ///
+    /// 语言方向明确不支持时直接回退，无法判断时先尝试领域翻译，返回方向不支持错误后再回退。
+    /// 实际使用的翻译方式记录在结果的`route`中
+    /// - q: 要翻译的文本
+    /// - domain: 选择垂直领域
+    /// - fallback: 回退方式
+    #[cfg(feature = "domain")]
+    pub async fn domain_translate_fallback(
+        &self,
+        q: &str,
+        domain: crate::domain::Domain,
+        fallback: crate::domain::fallback::Fallback,
+    ) -> anyhow::Result<crate::domain::model::DomainResult> {
+        use crate::domain::{
+            fallback::{self, Plan},
+            model::{DomainResult, Route},
+        };
+
+        let (from, to) = self.langs();
+
+        if domain.supports(from, to) != Some(false) {
+            let result = self
+                .domain_translate_with_lang(q, domain.clone(), from, to)
+                .await?;
+            if !result
+                .error_code
+                .as_ref()
+                .is_some_and(fallback::is_unsupported)
+            {
+                return Ok(result);
+            }
+        }
+
+        match fallback::plan(&domain, from, to, fallback) {
+            Plan::General => Ok(self.translate_with_lang(q, from, to).await?.into()),
+            Plan::PivotBefore(pivot) => {
+                let route = Route::Pivot {
+                    lang: pivot.to_string(),
+                };
+                let first = DomainResult::from(self.translate_with_lang(q, from, pivot).await?);
+                if fallback::failed(&first) {
+                    return Ok(DomainResult { route, ..first });
+                }
+
+                let second = self
+                    .domain_translate_with_lang(&fallback::dst_text(&first), domain, pivot, to)
+                    .await?;
+                Ok(fallback::merge(first, second, route))
+            }
+            Plan::PivotAfter(pivot) => {
+                let route = Route::Pivot {
+                    lang: pivot.to_string(),
+                };
+                let first = self
+                    .domain_translate_with_lang(q, domain, from, pivot)
+                    .await?;
+                if fallback::failed(&first) {
+                    return Ok(DomainResult { route, ..first });
+                }
+
+                let second = self
+                    .translate_with_lang(&fallback::dst_text(&first), pivot, to)
+                    .await?;
+                Ok(fallback::merge(first, second.into(), route))
+            }
+        }
+    }
+
+    /// 使用指定的语言方向进行通用翻译，不修改客户端配置
+    #[cfg(feature = "domain")]
+    async fn translate_with_lang(
+        &self,
+        q: &str,
+        from: Lang,
+        to: Lang,
+    ) -> anyhow::Result<CommonResult> {
+        let params = self.translate_form(q, from, to);
+
+        Ok(self
+            .send(self.http_client.post(COMMON_URL).form(&params))
+            .await?
+            .data)
+    }
+
+    /// 使用指定的语言方向进行垂直领域翻译，不修改客户端配置
+    #[cfg(feature = "domain")]
+    async fn domain_translate_with_lang(
+        &self,
+        q: &str,
+        domain: crate::domain::Domain,
+        from: Lang,
+        to: Lang,
+    ) -> anyhow::Result<crate::domain::model::DomainResult> {
+        use crate::constant::DOMAIN_URL;
 
-        Ok(resp.json().await?)
+        let params = self.domain_form(q, domain, from, to);
+
+        Ok(self
+            .send(self.http_client.post(DOMAIN_URL).form(&params))
+            .await?
+            .data)
+    }
+
+    /// 构建垂直领域翻译表单，语言方向由参数指定
+    #[cfg(feature = "domain")]
+    fn domain_form(
+        &self,
+        q: &str,
+        domain: crate::domain::Domain,
+        from: Lang,
+        to: Lang,
+    ) -> std::collections::HashMap<String, String> {
+        util::build_domain_form(&self.config.borrow(), q, domain, from, to)
+    }
+
+    /// 语音翻译
+    /// - request: 语音翻译请求，需要指定源语言和目标语言
+    #[cfg(feature = "voice")]
+    pub async fn voice_translate(
+        &self,
+        request: &crate::voice::VoiceRequest,
+    ) -> anyhow::Result<crate::voice::model::VoiceResult> {
+        Ok(self.voice_translate_raw(request).await?.data)
+    }
+
+    /// 语音翻译，返回原始响应信息
+    /// - request: 语音翻译请求，需要指定源语言和目标语言
+    #[cfg(feature = "voice")]
+    pub async fn voice_translate_raw(
+        &self,
+        request: &crate::voice::VoiceRequest,
+    ) -> anyhow::Result<Response<crate::voice::model::VoiceResult>> {
+        use crate::constant::VOICE_URL;
+
+        let (headers, body) = util::build_voice_request(&self.config.borrow(), request)?;
+        let mut builder = self.http_client.post(VOICE_URL).json(&body);
+        for (k, v) in headers {
+            builder = builder.header(k, v);
+        }
+
+        self.send(builder).await
+    }
+
+    /// 语音翻译，根据扩展名推断语音格式，WAV从文件头读取采样率，其他格式按16000Hz
+    /// - path: 语音文件路径
+    #[cfg(feature = "voice")]
+    pub async fn voice_translate_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<crate::voice::model::VoiceResult> {
+        Ok(self.voice_translate_file_raw(path).await?.data)
+    }
+
+    /// 语音翻译，根据扩展名推断语音格式，返回原始响应信息
+    /// - path: 语音文件路径
+    #[cfg(feature = "voice")]
+    pub async fn voice_translate_file_raw(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<Response<crate::voice::model::VoiceResult>> {
+        use crate::voice::{VoiceFormat, VoiceRequest};
+
+        let path = path.as_ref();
+        let format = VoiceFormat::from_name(path)
+            .ok_or_else(|| anyhow::anyhow!("无法识别的语音格式: {}", path.display()))?;
+        let data = fs::read(path).await?;
+
+        self.voice_translate_raw(&VoiceRequest::new(format, data))
+            .await
     }
 
     /// 文档翻译统计校验服务
     /// - `data`: 文件数据
     /// - `name`: 文件名
-    /// - `ext`: 文件扩展名
+    /// - `format`: 文档格式
     #[cfg(feature = "doc")]
     pub async fn doc_count_translate(
         &self,
         data: Vec<u8>,
         name: &str,
-        ext: &str,
+        format: crate::document::format::DocFormat,
     ) -> anyhow::Result<crate::document::model::DocCountResult> {
-        use crate::constant::DOC_COUNT_URL;
+        Ok(self.doc_count_translate_raw(data, name, format).await?.data)
+    }
 
-        let params = util::build_doc_count_form_aio(&self.config.borrow(), data, name, ext)?;
+    /// 文档翻译统计校验服务，返回原始响应信息
+    /// - `data`: 文件数据
+    /// - `name`: 文件名
+    /// - `format`: 文档格式
+    #[cfg(feature = "doc")]
+    pub async fn doc_count_translate_raw(
+        &self,
+        data: Vec<u8>,
+        name: &str,
+        format: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocCountResult>> {
+        let file_md5 = util::md5_hex(&data);
+        let part = reqwest::multipart::Part::bytes(data).file_name(name.to_string());
+        self.doc_count_part(part, &file_md5, format).await
+    }
 
-        let resp = self
-            .http_client
-            .post(DOC_COUNT_URL)
-            .multipart(params)
-            .send()
-            .await?;
+    /// 文档翻译统计校验服务，从文件中流式上传，根据文件头和扩展名推断文档格式
+    /// - `path`: 文档路径
+    #[cfg(feature = "doc")]
+    pub async fn doc_count_translate_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<crate::document::model::DocCountResult> {
+        Ok(self.doc_count_translate_file_raw(path).await?.data)
+    }
+
+    /// 文档翻译统计校验服务，从文件中流式上传，返回原始响应信息
+    /// - `path`: 文档路径
+    #[cfg(feature = "doc")]
+    pub async fn doc_count_translate_file_raw(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<Response<crate::document::model::DocCountResult>> {
+        let (file, name, format) = self.open_document(path.as_ref()).await?;
+        self.doc_count_translate_reader_raw(&name, file, format)
+            .await
+    }
 
-        Ok(resp.json().await?)
+    /// 文档翻译统计校验服务，从reader中流式上传
+    /// - `name`: 文件名
+    /// - `reader`: 文件数据
+    /// - `format`: 文档格式
+    #[cfg(feature = "doc")]
+    pub async fn doc_count_translate_reader<R>(
+        &self,
+        name: &str,
+        reader: R,
+        format: crate::document::format::DocFormat,
+    ) -> anyhow::Result<crate::document::model::DocCountResult>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Send + Sync + Unpin + 'static,
+    {
+        Ok(self
+            .doc_count_translate_reader_raw(name, reader, format)
+            .await?
+            .data)
+    }
+
+    /// 文档翻译统计校验服务，从reader中流式上传，返回原始响应信息
+    /// - `name`: 文件名
+    /// - `reader`: 文件数据
+    /// - `format`: 文档格式
+    #[cfg(feature = "doc")]
+    pub async fn doc_count_translate_reader_raw<R>(
+        &self,
+        name: &str,
+        reader: R,
+        format: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocCountResult>>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Send + Sync + Unpin + 'static,
+    {
+        let (part, file_md5) = util::reader_part_aio(name, reader).await?;
+        self.doc_count_part(part, &file_md5, format).await
+    }
+
+    #[cfg(feature = "doc")]
+    async fn doc_count_part(
+        &self,
+        part: reqwest::multipart::Part,
+        file_md5: &str,
+        format: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocCountResult>> {
+        use crate::constant::DOC_COUNT_URL;
+
+        let params = util::build_doc_count_form_aio(&self.config.borrow(), part, file_md5, format);
+
+        self.send(self.http_client.post(DOC_COUNT_URL).multipart(params))
+            .await
     }
 
     /// 文档翻译服务
     /// - `data`: 文件数据
     /// - `name`: 文件名
-    /// - `ext`: 文件扩展名
-    /// - `out_type`: 输出扩展名
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
     #[cfg(feature = "doc")]
     pub async fn doc_translate(
         &self,
         data: Vec<u8>,
         name: &str,
-        ext: &str,
-        out_type: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<crate::document::model::DocResult> {
+        Ok(self.doc_translate_raw(data, name, format, out).await?.data)
+    }
+
+    /// 文档翻译服务，返回原始响应信息
+    /// - `data`: 文件数据
+    /// - `name`: 文件名
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
+    #[cfg(feature = "doc")]
+    pub async fn doc_translate_raw(
+        &self,
+        data: Vec<u8>,
+        name: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
+        format.check_conversion(out)?;
+
+        let file_md5 = util::md5_hex(&data);
+        let part = reqwest::multipart::Part::bytes(data).file_name(name.to_string());
+        self.doc_translate_part(part, &file_md5, format, out, None)
+            .await
+    }
+
+    /// 文档翻译服务，从文件中流式上传，根据文件头和扩展名推断文档格式
+    /// - `path`: 文档路径
+    /// - `out`: 输出格式
+    #[cfg(feature = "doc")]
+    pub async fn doc_translate_file(
+        &self,
+        path: impl AsRef<Path>,
+        out: crate::document::format::DocFormat,
     ) -> anyhow::Result<crate::document::model::DocResult> {
+        Ok(self.doc_translate_file_raw(path, out).await?.data)
+    }
+
+    /// 文档翻译服务，从文件中流式上传，返回原始响应信息
+    /// - `path`: 文档路径
+    /// - `out`: 输出格式
+    #[cfg(feature = "doc")]
+    pub async fn doc_translate_file_raw(
+        &self,
+        path: impl AsRef<Path>,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
+        self.doc_translate_file_request(path.as_ref(), out, None)
+            .await
+    }
+
+    /// 文档翻译服务，从reader中流式上传
+    /// - `name`: 文件名
+    /// - `reader`: 文件数据
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
+    #[cfg(feature = "doc")]
+    pub async fn doc_translate_reader<R>(
+        &self,
+        name: &str,
+        reader: R,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<crate::document::model::DocResult>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Send + Sync + Unpin + 'static,
+    {
+        Ok(self
+            .doc_translate_reader_raw(name, reader, format, out)
+            .await?
+            .data)
+    }
+
+    /// 文档翻译服务，从reader中流式上传，返回原始响应信息
+    /// - `name`: 文件名
+    /// - `reader`: 文件数据
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
+    #[cfg(feature = "doc")]
+    pub async fn doc_translate_reader_raw<R>(
+        &self,
+        name: &str,
+        reader: R,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>>
+    where
+        R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Send + Sync + Unpin + 'static,
+    {
+        format.check_conversion(out)?;
+
+        let (part, file_md5) = util::reader_part_aio(name, reader).await?;
+        self.doc_translate_part(part, &file_md5, format, out, None)
+            .await
+    }
+
+    #[cfg(feature = "doc")]
+    async fn doc_translate_file_request(
+        &self,
+        path: &Path,
+        out: crate::document::format::DocFormat,
+        file_id: Option<&str>,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
+        let (file, name, format) = self.open_document(path).await?;
+        format.check_conversion(out)?;
+
+        let (part, file_md5) = util::reader_part_aio(&name, file).await?;
+        self.doc_translate_part(part, &file_md5, format, out, file_id)
+            .await
+    }
+
+    #[cfg(feature = "doc")]
+    async fn doc_translate_part(
+        &self,
+        part: reqwest::multipart::Part,
+        file_md5: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+        file_id: Option<&str>,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
         use crate::constant::DOC_URL;
 
-        let params = util::build_doc_form_aio(&self.config.borrow(), data, name, ext, out_type)?;
+        let params =
+            util::build_doc_form_aio(&self.config.borrow(), part, file_md5, format, out, file_id);
 
-        let resp = self
-            .http_client
-            .post(DOC_URL)
-            .multipart(params)
-            .send()
+        self.send(self.http_client.post(DOC_URL).multipart(params))
+            .await
+    }
+
+    /// 先统计报价，确认后再提交文档翻译
+    ///
+    /// 报价未通过确认时返回[`QuoteRejected`](crate::document::quote::QuoteRejected)错误，不会提交翻译。
+    /// 提交翻译时附带统计接口返回的`fileId`
+    /// - `data`: 文件数据
+    /// - `name`: 文件名
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
+    /// - `approve`: 确认报价，返回`true`时提交翻译，可以使用[`max_amount`](crate::document::quote::max_amount)
+    #[cfg(feature = "doc")]
+    pub async fn doc_translate_quoted(
+        &self,
+        data: Vec<u8>,
+        name: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+        approve: impl FnOnce(&crate::document::model::DocCountData) -> bool,
+    ) -> anyhow::Result<crate::document::model::DocResult> {
+        Ok(self
+            .doc_translate_quoted_raw(data, name, format, out, approve)
+            .await?
+            .data)
+    }
+
+    /// 先统计报价，确认后再提交文档翻译，返回提交翻译的原始响应信息
+    ///
+    /// 参数同[`doc_translate_quoted`](Self::doc_translate_quoted)
+    #[cfg(feature = "doc")]
+    pub async fn doc_translate_quoted_raw(
+        &self,
+        data: Vec<u8>,
+        name: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+        approve: impl FnOnce(&crate::document::model::DocCountData) -> bool,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
+        use crate::document::quote;
+
+        format.check_conversion(out)?;
+
+        // 统计和翻译读取同一份数据，避免在内存中保存两份文档
+        let data = util::SharedBuf::new(data);
+        let count = self
+            .doc_count_translate_reader(name, data.reader(), format)
+            .await?;
+        let quote = quote::approve(count, approve)?;
+
+        let file_id = Some(quote.file_id.as_str()).filter(|id| !id.is_empty());
+        let (part, file_md5) = util::reader_part_aio(name, data.reader()).await?;
+        self.doc_translate_part(part, &file_md5, format, out, file_id)
+            .await
+    }
+
+    /// 查询文档翻译任务状态
+    /// - `request_id`: 文档翻译返回的请求id
+    #[cfg(feature = "doc")]
+    pub async fn doc_query(
+        &self,
+        request_id: &str,
+    ) -> anyhow::Result<crate::document::model::DocQueryResult> {
+        Ok(self.doc_query_raw(request_id).await?.data)
+    }
+
+    /// 查询文档翻译任务状态，返回原始响应信息
+    /// - `request_id`: 文档翻译返回的请求id
+    #[cfg(feature = "doc")]
+    pub async fn doc_query_raw(
+        &self,
+        request_id: &str,
+    ) -> anyhow::Result<Response<crate::document::model::DocQueryResult>> {
+        use crate::constant::DOC_QUERY_URL;
+
+        let params = util::build_doc_query_form(&self.config.borrow(), request_id);
+
+        self.send(self.http_client.post(DOC_QUERY_URL).form(&params))
+            .await
+    }
+
+    /// 翻译文档并下载译文
+    ///
+    /// 从文件中流式上传文档，按[`PollOptions`](crate::document::job::PollOptions)查询任务状态，
+    /// 成功后下载译文到[`output_path`](crate::document::job::output_path)，返回译文路径。
+    /// 目标语言为自动时在提交前返回错误
+    /// - `path`: 文档路径，根据文件头和扩展名推断文档格式
+    /// - `out`: 输出格式
+    #[cfg(feature = "doc")]
+    pub async fn translate_document(
+        &self,
+        path: impl AsRef<Path>,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<std::path::PathBuf> {
+        let path = path.as_ref();
+        let output = crate::document::job::output_path(path, self.langs().1, out)?;
+        let result = self.doc_translate_file(path, out).await?;
+        self.doc_wait_download(result, output).await
+    }
+
+    /// 翻译文档并下载译文，提交前先统计报价并确认
+    ///
+    /// 报价未通过确认时返回[`QuoteRejected`](crate::document::quote::QuoteRejected)错误
+    /// - `path`: 文档路径，根据文件头和扩展名推断文档格式
+    /// - `out`: 输出格式
+    /// - `approve`: 确认报价，返回`true`时提交翻译
+    #[cfg(feature = "doc")]
+    pub async fn translate_document_quoted(
+        &self,
+        path: impl AsRef<Path>,
+        out: crate::document::format::DocFormat,
+        approve: impl FnOnce(&crate::document::model::DocCountData) -> bool,
+    ) -> anyhow::Result<std::path::PathBuf> {
+        use crate::document::quote;
+
+        let path = path.as_ref();
+        let output = crate::document::job::output_path(path, self.langs().1, out)?;
+        let (file, name, format) = self.open_document(path).await?;
+        format.check_conversion(out)?;
+
+        let count = self.doc_count_translate_reader(&name, file, format).await?;
+        let quote = quote::approve(count, approve)?;
+
+        let file_id = Some(quote.file_id.as_str()).filter(|id| !id.is_empty());
+        let result = self
+            .doc_translate_file_request(path, out, file_id)
+            .await?
+            .data;
+        self.doc_wait_download(result, output).await
+    }
+
+    /// 批量报价目录中的文档，报价记录在输出目录的清单中
+    ///
+    /// 已报价且文件没有变化的文档不会重复报价
+    /// - dir: 文档目录
+    /// - out_dir: 输出目录，保存清单和译文
+    /// - options: 批量翻译参数
+    #[cfg(all(feature = "batch", feature = "doc"))]
+    pub async fn doc_quote_dir(
+        &self,
+        dir: impl AsRef<Path>,
+        out_dir: impl AsRef<Path>,
+        options: &crate::document::batch::DocBatchOptions,
+    ) -> anyhow::Result<crate::document::batch::DirQuote> {
+        let manifest = self
+            .doc_batch_quote(dir.as_ref(), out_dir.as_ref(), options, &mut |_, _| {})
+            .await?;
+        Ok(manifest.quote())
+    }
+
+    /// 批量翻译目录中的文档，译文按原目录结构保存到输出目录
+    ///
+    /// 先对所有文档报价，`approve`确认的文档才会提交翻译，之后按
+    /// [`PollOptions`](crate::document::job::PollOptions)查询并下载译文。
+    /// 每一步都会更新输出目录中的清单，中断后再次调用会从清单继续
+    /// - dir: 文档目录
+    /// - out_dir: 输出目录，保存清单和译文
+    /// - options: 批量翻译参数
+    /// - approve: 确认单个文档的报价，参数为相对路径和报价
+    /// - progress: 文档状态变化时调用，参数为相对路径和新状态
+    #[cfg(all(feature = "batch", feature = "doc"))]
+    pub async fn doc_translate_dir(
+        &self,
+        dir: impl AsRef<Path>,
+        out_dir: impl AsRef<Path>,
+        options: &crate::document::batch::DocBatchOptions,
+        mut approve: impl FnMut(&str, &crate::document::model::DocCountData) -> bool,
+        mut progress: impl FnMut(&str, &crate::document::batch::JobState),
+    ) -> anyhow::Result<crate::document::batch::DocBatchReport> {
+        use crate::document::{
+            batch::{self, DocBatchReport, JobState},
+            job,
+        };
+
+        let (dir, out_dir) = (dir.as_ref(), out_dir.as_ref());
+        let manifest_path = batch::manifest_path(out_dir);
+        let mut manifest = self
+            .doc_batch_quote(dir, out_dir, options, &mut progress)
+            .await?;
+        let mut report = DocBatchReport {
+            quote: manifest.quote(),
+            ..Default::default()
+        };
+
+        // 提交通过确认的文档
+        let keys: Vec<String> = manifest.entries.keys().cloned().collect();
+        for key in keys.iter() {
+            let entry = &manifest.entries[key];
+            if !matches!(entry.state, JobState::Quoted | JobState::Declined) {
+                continue;
+            }
+
+            let state = if !approve(key, &entry.quote()) {
+                JobState::Declined
+            } else {
+                let file_id = Some(entry.file_id.as_str()).filter(|id| !id.is_empty());
+                let submitted = match entry.formats() {
+                    Ok((_, out)) => {
+                        self.doc_translate_file_request(&dir.join(key), out, file_id)
+                            .await
+                    }
+                    Err(err) => Err(err),
+                };
+                // 读取文档或请求失败时只记录该文档失败，继续提交其他文档
+                match submitted {
+                    Ok(Response { data: result, .. }) => match result.data {
+                        Some(data) if result.error_code.is_success() => {
+                            manifest.entries.get_mut(key).unwrap().request_id = data.request_id;
+                            JobState::Submitted
+                        }
+                        _ => {
+                            JobState::Failed(format!("{}: {}", result.error_code, result.error_msg))
+                        }
+                    },
+                    Err(err) => JobState::Failed(err.to_string()),
+                }
+            };
+
+            progress(key, &state);
+            manifest.entries.get_mut(key).unwrap().state = state;
+            manifest.save(&manifest_path).await?;
+        }
+
+        // 查询已提交的文档并下载译文
+        let options = self.config.borrow().doc_poll;
+        let start = Instant::now();
+        let mut interval = options.initial_interval;
+        loop {
+            let submitted: Vec<String> = keys
+                .iter()
+                .filter(|key| manifest.entries[*key].state == JobState::Submitted)
+                .cloned()
+                .collect();
+            if submitted.is_empty() {
+                break;
+            }
+            if options
+                .timeout
+                .is_some_and(|timeout| start.elapsed() >= timeout)
+            {
+                report.pending = submitted;
+                break;
+            }
+
+            tokio::time::sleep(interval).await;
+            for key in submitted {
+                let entry = &manifest.entries[&key];
+                let result = self.doc_query(&entry.request_id).await?;
+                let state = if !result.error_code.is_success() {
+                    JobState::Failed(format!("{}: {}", result.error_code, result.error_msg))
+                } else {
+                    match result.data.filter(|data| data.status.is_finished()) {
+                        None => continue,
+                        Some(data) => match job::finished_url(&data) {
+                            Err(err) => JobState::Failed(err.to_string()),
+                            Ok(url) => {
+                                let (_, out) = entry.formats()?;
+                                let output = batch::mirror_path(out_dir, &key, out);
+                                let resp = self.http_client.get(url).send().await?;
+                                let data = resp.error_for_status()?.bytes().await?;
+                                if let Some(parent) = output.parent() {
+                                    fs::create_dir_all(parent).await?;
+                                }
+                                fs::write(&output, data).await?;
+                                report.downloaded.push(output);
+                                JobState::Downloaded
+                            }
+                        },
+                    }
+                };
+
+                progress(&key, &state);
+                manifest.entries.get_mut(&key).unwrap().state = state;
+                manifest.save(&manifest_path).await?;
+            }
+            interval = options.next_interval(interval);
+        }
+
+        for (key, entry) in manifest.entries {
+            match entry.state {
+                JobState::Declined => report.declined.push(key),
+                JobState::Failed(reason) => report.failed.push((key, reason)),
+                _ => {}
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// 同步清单并对未报价的文档报价
+    #[cfg(all(feature = "batch", feature = "doc"))]
+    async fn doc_batch_quote(
+        &self,
+        dir: &Path,
+        out_dir: &Path,
+        options: &crate::document::batch::DocBatchOptions,
+        progress: &mut impl FnMut(&str, &crate::document::batch::JobState),
+    ) -> anyhow::Result<crate::document::batch::Manifest> {
+        use crate::document::batch::{self, JobState, Manifest};
+
+        let manifest_path = batch::manifest_path(out_dir);
+        fs::create_dir_all(out_dir).await?;
+
+        let mut manifest = Manifest::load(&manifest_path).await?;
+        let (from, to) = self.langs();
+        manifest.reset_lang(&from.to_string(), &to.to_string());
+        manifest.sync(batch::collect(dir, out_dir, options)?);
+        manifest.save(&manifest_path).await?;
+
+        let pending: Vec<String> = manifest
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.state == JobState::Pending)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in pending {
+            let result = self.doc_count_translate_file(dir.join(&key)).await?;
+            let entry = manifest.entries.get_mut(&key).unwrap();
+            entry.state = match result.data {
+                Some(data) if result.error_code.is_success() => {
+                    entry.char_count = data.char_count;
+                    entry.amount = data.amount;
+                    entry.file_id = data.file_id;
+                    JobState::Quoted
+                }
+                _ => JobState::Failed(format!("{}: {}", result.error_code, result.error_msg)),
+            };
+            progress(&key, &entry.state);
+            manifest.save(&manifest_path).await?;
+        }
+
+        Ok(manifest)
+    }
+
+    /// 等待文档翻译完成并下载译文
+    #[cfg(feature = "doc")]
+    async fn doc_wait_download(
+        &self,
+        result: crate::document::model::DocResult,
+        output: std::path::PathBuf,
+    ) -> anyhow::Result<std::path::PathBuf> {
+        use crate::document::job;
+
+        let request_id = match result.data {
+            Some(data) if result.error_code.is_success() => data.request_id,
+            _ => anyhow::bail!("{}: {}", result.error_code, result.error_msg),
+        };
+
+        let options = self.config.borrow().doc_poll;
+        let start = Instant::now();
+        let mut interval = options.initial_interval;
+        let url = loop {
+            tokio::time::sleep(interval).await;
+
+            let result = self.doc_query(&request_id).await?;
+            if !result.error_code.is_success() {
+                anyhow::bail!("{}: {}", result.error_code, result.error_msg);
+            }
+            if let Some(data) = result.data.filter(|data| data.status.is_finished()) {
+                break job::finished_url(&data)?.to_string();
+            }
+
+            if options
+                .timeout
+                .is_some_and(|timeout| start.elapsed() >= timeout)
+            {
+                anyhow::bail!("文档翻译超时: {}", request_id);
+            }
+            interval = options.next_interval(interval);
+        };
+
+        let resp = self.http_client.get(url).send().await?.error_for_status()?;
+        let data = resp.bytes().await?;
+        fs::write(&output, data).await?;
+
+        Ok(output)
+    }
+
+    /// 打开文档，根据文件头和扩展名推断文档格式
+    #[cfg(feature = "doc")]
+    async fn open_document(
+        &self,
+        path: &Path,
+    ) -> anyhow::Result<(fs::File, String, crate::document::format::DocFormat)> {
+        use std::io::SeekFrom;
+
+        use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+        use crate::document::job;
+
+        let mut file = fs::File::open(path).await?;
+        let mut head = Vec::new();
+        (&mut file)
+            .take(job::SNIFF_LEN)
+            .read_to_end(&mut head)
             .await?;
+        file.seek(SeekFrom::Start(0)).await?;
 
-        Ok(resp.json().await?)
+        let (name, format) = job::name_and_format(path, &head)?;
+        Ok((file, name, format))
     }
 }
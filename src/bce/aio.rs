@@ -0,0 +1,109 @@
+//! 百度智能云异步API
+use std::{cell::RefCell, time::Instant};
+
+use serde::de::DeserializeOwned;
+
+use super::{model::BceResult, AccessToken, BceConfig};
+use crate::{
+    constant::{BCE_TEXT_URL, BCE_TOKEN_URL},
+    lang::Lang,
+    model::CommonResult,
+    response::Response,
+};
+
+/// 百度智能云翻译客户端
+pub struct Client {
+    pub(crate) config: RefCell<BceConfig>,
+    pub(crate) token: RefCell<Option<AccessToken>>,
+    pub(crate) http_client: reqwest::Client,
+}
+
+impl Client {
+    /// 创建客户端
+    pub fn new(config: BceConfig) -> Self {
+        Self {
+            config: RefCell::new(config),
+            token: RefCell::new(None),
+            http_client: reqwest::Client::builder().build().unwrap(),
+        }
+    }
+
+    /// 设置源语言和目标语言
+    pub fn lang(&self, from: Lang, to: Lang) {
+        self.config.borrow_mut().set_from(from);
+        self.config.borrow_mut().set_to(to);
+    }
+
+    /// 获取access token，优先使用缓存，即将过期时刷新
+    pub async fn access_token(&self) -> anyhow::Result<String> {
+        if let Some(token) = self.token.borrow().as_ref().filter(|t| !t.is_expiring()) {
+            return Ok(token.token.clone());
+        }
+
+        self.refresh_token().await
+    }
+
+    /// 重新获取access token
+    pub async fn refresh_token(&self) -> anyhow::Result<String> {
+        let params = self.config.borrow().clone();
+        let resp: Response<super::model::TokenResult> = self
+            .send(
+                self.http_client
+                    .post(BCE_TOKEN_URL)
+                    .query(&params.token_params()),
+            )
+            .await?;
+
+        let token = AccessToken::try_from(resp.data)?;
+        let value = token.token.clone();
+        *self.token.borrow_mut() = Some(token);
+        Ok(value)
+    }
+
+    /// 发送请求并解析响应
+    async fn send<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> anyhow::Result<Response<T>> {
+        let start = Instant::now();
+        let resp = request.send().await?;
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let body = resp.bytes().await?.to_vec();
+
+        Response::decode(status, headers, body, start.elapsed())
+    }
+}
+
+impl Client {
+    /// 文本翻译
+    /// - q: 源文本
+    pub async fn translate(&self, q: &str) -> anyhow::Result<CommonResult> {
+        Ok(self.translate_raw(q).await?.data)
+    }
+
+    /// 文本翻译，返回原始响应信息
+    ///
+    /// access token失效时刷新后重试一次
+    /// - q: 源文本
+    pub async fn translate_raw(&self, q: &str) -> anyhow::Result<Response<CommonResult>> {
+        let mut resp = self.translate_bce(q, self.access_token().await?).await?;
+        if resp.data.is_token_invalid() {
+            resp = self.translate_bce(q, self.refresh_token().await?).await?;
+        }
+
+        Ok(resp.map(Into::into))
+    }
+
+    async fn translate_bce(&self, q: &str, token: String) -> anyhow::Result<Response<BceResult>> {
+        let body = self.config.borrow().translate_body(q);
+
+        self.send(
+            self.http_client
+                .post(BCE_TEXT_URL)
+                .query(&[("access_token", token)])
+                .json(&body),
+        )
+        .await
+    }
+}
@@ -0,0 +1,119 @@
+//! 百度智能云(BCE)机器翻译
+//!
+//! 百度智能云的文本翻译接口使用API Key和Secret Key换取access token鉴权，
+//! 与百度翻译开放平台的`sign`签名不同。客户端会缓存access token，过期前自动刷新，
+//! 接口返回token失效时刷新后重试一次。
+//!
+//! 具体使用请查看: [https://cloud.baidu.com/doc/MT/s/4kqryjku9]
+//!
+//! 需要开启`bce` feature
+//!
+//! ```toml
+//! baidu_trans = { version = "0.8.0", features = ["bce"] }
+//! ```
+use std::time::{Duration, Instant};
+
+use crate::lang::Lang;
+
+#[cfg(feature = "aio")]
+pub mod aio;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod model;
+
+/// token剩余有效期小于该值时提前刷新
+const REFRESH_BEFORE: Duration = Duration::from_secs(5 * 60);
+
+/// 百度智能云客户端配置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BceConfig {
+    /// API Key
+    pub api_key: String,
+    /// Secret Key
+    pub secret_key: String,
+    /// 源语言，默认auto
+    pub from: Lang,
+    /// 目标语言，默认auto
+    pub to: Lang,
+    /// 术语库id，为空时不使用术语库
+    pub term_ids: Vec<String>,
+}
+
+impl BceConfig {
+    /// 创建配置
+    pub fn new(api_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            secret_key: secret_key.into(),
+            from: Lang::default(),
+            to: Lang::default(),
+            term_ids: Vec::new(),
+        }
+    }
+
+    /// 设置源语言
+    pub fn set_from(&mut self, from: Lang) {
+        self.from = from;
+    }
+
+    /// 设置目标语言
+    pub fn set_to(&mut self, to: Lang) {
+        self.to = to;
+    }
+
+    /// 获取access token的查询参数
+    pub(crate) fn token_params(&self) -> [(&'static str, &str); 3] {
+        [
+            ("grant_type", "client_credentials"),
+            ("client_id", &self.api_key),
+            ("client_secret", &self.secret_key),
+        ]
+    }
+
+    /// 文本翻译请求体
+    pub(crate) fn translate_body(&self, q: &str) -> serde_json::Value {
+        let mut body = serde_json::json!({
+            "q": q,
+            "from": self.from.to_string(),
+            "to": self.to.to_string(),
+        });
+        if !self.term_ids.is_empty() {
+            body["termIds"] = self.term_ids.join(",").into();
+        }
+        body
+    }
+}
+
+/// 缓存的access token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessToken {
+    /// access token
+    pub token: String,
+    /// 过期时间
+    pub expires_at: Instant,
+}
+
+impl AccessToken {
+    /// 是否需要刷新
+    pub fn is_expiring(&self) -> bool {
+        self.expires_at.saturating_duration_since(Instant::now()) < REFRESH_BEFORE
+    }
+}
+
+impl TryFrom<model::TokenResult> for AccessToken {
+    type Error = anyhow::Error;
+
+    fn try_from(result: model::TokenResult) -> Result<Self, Self::Error> {
+        match result.access_token {
+            Some(token) => Ok(AccessToken {
+                token,
+                expires_at: Instant::now() + Duration::from_secs(result.expires_in),
+            }),
+            None => anyhow::bail!(
+                "获取access token失败: {} {}",
+                result.error.unwrap_or_default(),
+                result.error_description.unwrap_or_default()
+            ),
+        }
+    }
+}
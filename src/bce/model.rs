@@ -0,0 +1,86 @@
+//! 百度智能云返回相关结构
+
+use serde::Deserialize;
+
+use crate::model::{CommonResult, ErrorCode, TranslateResult};
+
+/// access token返回结果
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TokenResult {
+    /// access token
+    pub access_token: Option<String>,
+    /// 有效期(秒)
+    #[serde(default)]
+    pub expires_in: u64,
+    /// 错误类型
+    pub error: Option<String>,
+    /// 错误描述
+    pub error_description: Option<String>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 文本翻译返回结果
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BceResult {
+    /// 翻译结果
+    pub result: Option<BceData>,
+    /// 错误码
+    pub error_code: Option<ErrorCode>,
+    /// 错误消息
+    pub error_msg: Option<String>,
+    /// 请求日志id
+    pub log_id: Option<u64>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 文本翻译结果数据
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BceData {
+    /// 源语言
+    pub from: Option<String>,
+    /// 目标语言
+    pub to: Option<String>,
+    /// 翻译结果
+    pub trans_result: Option<Vec<TranslateResult>>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl BceResult {
+    /// access token无效或过期
+    pub fn is_token_invalid(&self) -> bool {
+        self.error_code
+            .as_ref()
+            .is_some_and(|code| *code == 110 || *code == 111)
+    }
+}
+
+impl From<BceResult> for CommonResult {
+    /// 转换为通用翻译结果，`log_id`保留在`extra`中
+    fn from(result: BceResult) -> Self {
+        let data = result.result.unwrap_or_default();
+        let mut extra = data.extra;
+        extra.extend(result.extra);
+        if let Some(log_id) = result.log_id {
+            extra.insert("log_id".to_string(), log_id.into());
+        }
+
+        CommonResult {
+            from: data.from,
+            to: data.to,
+            trans_result: data.trans_result,
+            error_code: result.error_code,
+            error_msg: result.error_msg,
+            extra,
+            ..Default::default()
+        }
+    }
+}
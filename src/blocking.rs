@@ -1,7 +1,11 @@
 //! 阻塞API
-use std::cell::RefCell;
+use std::{cell::RefCell, fs, path::Path, time::Instant};
 
-use crate::{config::Config, constant::COMMON_URL, lang::Lang, model::CommonResult, util};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    config::Config, constant::COMMON_URL, lang::Lang, model::CommonResult, response::Response, util,
+};
 
 /// 百度翻译客户端
 pub struct Client {
@@ -23,17 +27,196 @@ impl Client {
         self.config.borrow_mut().set_from(from);
         self.config.borrow_mut().set_to(to);
     }
+
+    /// 当前的源语言和目标语言
+    fn langs(&self) -> (Lang, Lang) {
+        let config = self.config.borrow();
+        (config.from, config.to)
+    }
+
+    /// 构建通用翻译表单，语言方向由参数指定
+    fn translate_form(
+        &self,
+        q: &str,
+        from: Lang,
+        to: Lang,
+    ) -> std::collections::HashMap<String, String> {
+        util::build_form(&self.config.borrow(), q, from, to)
+    }
+
+    /// 设置图片翻译请求参数，如图片贴合类型
+    #[cfg(feature = "image")]
+    pub fn image_request(&self, request: crate::image::ImageRequest) {
+        self.config.borrow_mut().image = request;
+    }
+
+    /// 设置文档翻译任务的轮询参数
+    #[cfg(feature = "doc")]
+    pub fn doc_poll(&self, options: crate::document::job::PollOptions) {
+        self.config.borrow_mut().doc_poll = options;
+    }
+
+    /// 发送请求并解析响应
+    fn send<T: DeserializeOwned>(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> anyhow::Result<Response<T>> {
+        let start = Instant::now();
+        let resp = request.send()?;
+        let status = resp.status();
+        let headers = resp.headers().clone();
+        let body = resp.bytes()?.to_vec();
+
+        Response::decode(status, headers, body, start.elapsed())
+    }
 }
 
 impl Client {
     /// 通用翻译
     /// - q: 源文本
     pub fn translate(&self, q: &str) -> anyhow::Result<CommonResult> {
-        let params = util::build_form(&self.config.borrow(), q);
+        Ok(self.translate_raw(q)?.data)
+    }
+
+    /// 通用翻译，返回原始响应信息
+    /// - q: 源文本
+    pub fn translate_raw(&self, q: &str) -> anyhow::Result<Response<CommonResult>> {
+        let (from, to) = self.langs();
+        let params = self.translate_form(q, from, to);
+
+        self.send(self.http_client.post(COMMON_URL).form(&params))
+    }
+
+    /// 使用客户端术语表进行通用翻译
+    ///
+    /// 原文中的术语先替换为占位符，翻译后还原为指定的译文，
+    /// 被百度改写的占位符记录在[`GlossaryResult::mangled`](crate::glossary::GlossaryResult::mangled)中
+    /// - q: 源文本
+    /// - glossary: 术语表
+    #[cfg(feature = "glossary")]
+    pub fn translate_glossary(
+        &self,
+        q: &str,
+        glossary: &crate::glossary::Glossary,
+    ) -> anyhow::Result<crate::glossary::GlossaryResult> {
+        Ok(self.translate_glossary_raw(q, glossary)?.data)
+    }
+
+    /// 使用客户端术语表进行通用翻译，返回原始响应信息
+    ///
+    /// 原始响应体中是占位符，解析后的返回结果中占位符已还原
+    /// - q: 源文本
+    /// - glossary: 术语表
+    #[cfg(feature = "glossary")]
+    pub fn translate_glossary_raw(
+        &self,
+        q: &str,
+        glossary: &crate::glossary::Glossary,
+    ) -> anyhow::Result<Response<crate::glossary::GlossaryResult>> {
+        let (from, to) = self.langs();
+        let protected = glossary.protect(q, from, to);
+        let result = self.translate_raw(&protected.text)?;
+
+        Ok(result.map(|result| protected.restore_result(result)))
+    }
+
+    /// 大模型文本翻译
+    /// - q: 源文本
+    /// - request: 大模型翻译请求参数
+    #[cfg(feature = "llm")]
+    pub fn llm_translate(
+        &self,
+        q: &str,
+        request: &crate::llm::LlmRequest,
+    ) -> anyhow::Result<crate::llm::model::LlmResult> {
+        Ok(self.llm_translate_raw(q, request)?.data)
+    }
+
+    /// 大模型文本翻译，返回原始响应信息
+    /// - q: 源文本
+    /// - request: 大模型翻译请求参数
+    #[cfg(feature = "llm")]
+    pub fn llm_translate_raw(
+        &self,
+        q: &str,
+        request: &crate::llm::LlmRequest,
+    ) -> anyhow::Result<Response<crate::llm::model::LlmResult>> {
+        use crate::constant::LLM_URL;
+
+        let body = util::build_llm_body(&self.config.borrow(), q, request);
+
+        self.send(self.http_client.post(LLM_URL).json(&body))
+    }
+
+    /// 下载TTS音频，返回mp3数据
+    /// - url: `src_tts`或`dst_tts`链接
+    pub fn tts(&self, url: &str) -> anyhow::Result<Vec<u8>> {
+        let resp = self.http_client.get(url).send()?.error_for_status()?;
+
+        Ok(resp.bytes()?.to_vec())
+    }
+
+    /// 下载TTS音频并保存到指定路径
+    /// - url: `src_tts`或`dst_tts`链接
+    /// - path: 保存路径
+    pub fn save_tts(&self, url: &str, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let data = self.tts(url)?;
+        fs::write(path, data)?;
+
+        Ok(())
+    }
+
+    /// 获取原文发音，设置了`tts_cache_dir`时优先读取缓存
+    ///
+    /// 翻译结果中没有原文tts链接时返回`Ok(None)`
+    pub fn src_tts(&self, result: &CommonResult) -> anyhow::Result<Option<Vec<u8>>> {
+        match &result.src_tts {
+            Some(url) => {
+                let lang = result.from.as_deref().unwrap_or_default();
+                Ok(Some(self.cached_tts(url, &result.src_text(), lang)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// 获取译文发音，设置了`tts_cache_dir`时优先读取缓存
+    ///
+    /// 翻译结果中没有译文tts链接时返回`Ok(None)`
+    pub fn dst_tts(&self, result: &CommonResult) -> anyhow::Result<Option<Vec<u8>>> {
+        match &result.dst_tts {
+            Some(url) => {
+                let lang = result.to.as_deref().unwrap_or_default();
+                Ok(Some(self.cached_tts(url, &result.dst_text(), lang)?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn cached_tts(&self, url: &str, text: &str, lang: &str) -> anyhow::Result<Vec<u8>> {
+        let cache_dir = self.config.borrow().tts_cache_dir.clone();
+        let Some(cache_dir) = cache_dir else {
+            return self.tts(url);
+        };
+
+        let path = util::tts_cache_path(&cache_dir, text, lang);
+        if let Ok(data) = fs::read(&path) {
+            return Ok(data);
+        }
 
-        let resp = self.http_client.post(COMMON_URL).form(&params).send()?;
+        let data = self.tts(url)?;
+        // 先写入临时文件再重命名，避免中断时留下不完整的缓存；
+        // 同一文本并发下载时各自使用不同的临时文件，重命名失败但缓存已存在时视为命中
+        let tmp = util::temp_path(&path);
+        fs::create_dir_all(&cache_dir)?;
+        fs::write(&tmp, &data)?;
+        if let Err(err) = fs::rename(&tmp, &path) {
+            let _ = fs::remove_file(&tmp);
+            if fs::metadata(&path).is_err() {
+                return Err(err.into());
+            }
+        }
 
-        Ok(resp.json()?)
+        Ok(data)
     }
 
     /// 图片翻译
@@ -45,22 +228,164 @@ impl Client {
         name: &str,
         data: Vec<u8>,
     ) -> anyhow::Result<crate::image::model::ImageResult> {
-        let multipart_form = util::create_image_form_blocking(
-            name,
-            data,
-            &self.config.borrow(),
-            "APICUID",
-            "mac",
-            "3",
-            None,
-        );
-        let resp = self
-            .http_client
-            .post(crate::constant::IMAGE_URL)
-            .multipart(multipart_form)
-            .send()?;
+        Ok(self.image_translate_raw(name, data)?.data)
+    }
+
+    /// 图片翻译，返回原始响应信息
+    /// - name: 文件名
+    /// - data: 图片数据
+    #[cfg(feature = "image")]
+    pub fn image_translate_raw(
+        &self,
+        name: &str,
+        data: Vec<u8>,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>> {
+        let (from, to) = self.langs();
+        self.image_translate_with_lang(name, data, from, to)
+    }
+
+    /// 图片翻译，从文件中流式上传图片，文件名作为上传的文件名
+    /// - path: 图片路径
+    #[cfg(feature = "image")]
+    pub fn image_translate_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<crate::image::model::ImageResult> {
+        Ok(self.image_translate_file_raw(path)?.data)
+    }
+
+    /// 图片翻译，从文件中流式上传图片，返回原始响应信息
+    /// - path: 图片路径
+    #[cfg(feature = "image")]
+    pub fn image_translate_file_raw(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>> {
+        let path = path.as_ref();
+        let name = crate::image::file_name(path)?;
 
-        Ok(resp.json()?)
+        self.image_translate_reader_raw(&name, fs::File::open(path)?)
+    }
+
+    /// 图片翻译，从reader中流式上传图片
+    ///
+    /// 签名需要图片的md5，所以会先读取一遍计算md5，再回到开头上传
+    /// - name: 文件名
+    /// - reader: 图片数据
+    #[cfg(feature = "image")]
+    pub fn image_translate_reader<R>(
+        &self,
+        name: &str,
+        reader: R,
+    ) -> anyhow::Result<crate::image::model::ImageResult>
+    where
+        R: std::io::Read + std::io::Seek + Send + 'static,
+    {
+        Ok(self.image_translate_reader_raw(name, reader)?.data)
+    }
+
+    /// 图片翻译，从reader中流式上传图片，返回原始响应信息
+    /// - name: 文件名
+    /// - reader: 图片数据
+    #[cfg(feature = "image")]
+    pub fn image_translate_reader_raw<R>(
+        &self,
+        name: &str,
+        reader: R,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>>
+    where
+        R: std::io::Read + std::io::Seek + Send + 'static,
+    {
+        // 预处理需要完整的图片数据
+        #[cfg(feature = "preprocess")]
+        if self.config.borrow().image.preprocess.is_some() {
+            let mut reader = reader;
+            let mut data = Vec::new();
+            reader.read_to_end(&mut data)?;
+            return self.image_translate_raw(name, data);
+        }
+
+        let (part, image_md5) = util::reader_part_blocking(name, reader)?;
+        let (from, to) = self.langs();
+        self.image_translate_part(part, &image_md5, from, to)
+    }
+
+    #[cfg(feature = "image")]
+    fn image_translate_part(
+        &self,
+        part: reqwest::blocking::multipart::Part,
+        image_md5: &str,
+        from: Lang,
+        to: Lang,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>> {
+        let multipart_form =
+            util::create_image_form_blocking(part, image_md5, from, to, &self.config.borrow());
+
+        self.send(
+            self.http_client
+                .post(crate::constant::IMAGE_URL)
+                .multipart(multipart_form),
+        )
+    }
+
+    #[cfg(feature = "image")]
+    fn image_translate_with_lang(
+        &self,
+        name: &str,
+        data: Vec<u8>,
+        from: Lang,
+        to: Lang,
+    ) -> anyhow::Result<Response<crate::image::model::ImageResult>> {
+        use reqwest::blocking::multipart::Part;
+
+        #[cfg(feature = "preprocess")]
+        let (name, data, prepared) =
+            crate::image::prepare(&self.config.borrow().image, name, data)?;
+
+        let image_md5 = util::md5_hex(&data);
+        let part = Part::bytes(data).file_name(name.to_string());
+        let resp = self.image_translate_part(part, &image_md5, from, to)?;
+
+        #[cfg(feature = "preprocess")]
+        let resp = {
+            let mut resp = resp;
+            if let Some(prepared) = prepared {
+                prepared.restore(&mut resp.data);
+            }
+            resp
+        };
+
+        Ok(resp)
+    }
+
+    /// 文字识别，调用图片翻译接口并只保留识别出的原文及其位置
+    ///
+    /// 目标语言为自动时使用一个与源语言不同的语种
+    /// - name: 文件名
+    /// - data: 图片数据
+    #[cfg(feature = "image")]
+    pub fn image_ocr(
+        &self,
+        name: &str,
+        data: Vec<u8>,
+    ) -> anyhow::Result<crate::image::ocr::OcrResult> {
+        Ok(self.image_ocr_raw(name, data)?.data)
+    }
+
+    /// 文字识别，返回原始响应信息
+    /// - name: 文件名
+    /// - data: 图片数据
+    #[cfg(feature = "image")]
+    pub fn image_ocr_raw(
+        &self,
+        name: &str,
+        data: Vec<u8>,
+    ) -> anyhow::Result<Response<crate::image::ocr::OcrResult>> {
+        let (from, to) = self.langs();
+        let to = crate::image::ocr::target_lang(from, to);
+        let resp = self.image_translate_with_lang(name, data, from, to)?;
+
+        Ok(resp.map(Into::into))
     }
 
     /// 垂直领域翻译
@@ -71,59 +396,611 @@ impl Client {
         &self,
         q: &str,
         domain: crate::domain::Domain,
+    ) -> anyhow::Result<crate::domain::model::DomainResult> {
+        Ok(self.domain_translate_raw(q, domain)?.data)
+    }
+
+    /// 垂直领域翻译，返回原始响应信息
+    /// - q: 要翻译的文本
+    /// - domain: 选择垂直领域
+    #[cfg(feature = "domain")]
+    pub fn domain_translate_raw(
+        &self,
+        q: &str,
+        domain: crate::domain::Domain,
+    ) -> anyhow::Result<Response<crate::domain::model::DomainResult>> {
+        use crate::constant::DOMAIN_URL;
+
+        let (from, to) = self.langs();
+        let params = self.domain_form(q, domain, from, to);
+
+        self.send(self.http_client.post(DOMAIN_URL).form(&params))
+    }
+
+    /// 垂直领域翻译，领域不支持当前语言方向时回退到通用翻译
+    ///
+    /// 语言方向明确不支持时直接回退，无法判断时先尝试领域翻译，返回方向不支持错误后再回退。
+    /// 实际使用的翻译方式记录在结果的`route`中
+    /// - q: 要翻译的文本
+    /// - domain: 选择垂直领域
+    /// - fallback: 回退方式
+    #[cfg(feature = "domain")]
+    pub fn domain_translate_fallback(
+        &self,
+        q: &str,
+        domain: crate::domain::Domain,
+        fallback: crate::domain::fallback::Fallback,
+    ) -> anyhow::Result<crate::domain::model::DomainResult> {
+        use crate::domain::{
+            fallback::{self, Plan},
+            model::{DomainResult, Route},
+        };
+
+        let (from, to) = self.langs();
+
+        if domain.supports(from, to) != Some(false) {
+            let result = self.domain_translate_with_lang(q, domain.clone(), from, to)?;
+            if !result
+                .error_code
+                .as_ref()
+                .is_some_and(fallback::is_unsupported)
+            {
+                return Ok(result);
+            }
+        }
+
+        match fallback::plan(&domain, from, to, fallback) {
+            Plan::General => Ok(self.translate_with_lang(q, from, to)?.into()),
+            Plan::PivotBefore(pivot) => {
+                let route = Route::Pivot {
+                    lang: pivot.to_string(),
+                };
+                let first = DomainResult::from(self.translate_with_lang(q, from, pivot)?);
+                if fallback::failed(&first) {
+                    return Ok(DomainResult { route, ..first });
+                }
+
+                let second = self.domain_translate_with_lang(
+                    &fallback::dst_text(&first),
+                    domain,
+                    pivot,
+                    to,
+                )?;
+                Ok(fallback::merge(first, second, route))
+            }
+            Plan::PivotAfter(pivot) => {
+                let route = Route::Pivot {
+                    lang: pivot.to_string(),
+                };
+                let first = self.domain_translate_with_lang(q, domain, from, pivot)?;
+                if fallback::failed(&first) {
+                    return Ok(DomainResult { route, ..first });
+                }
+
+                let second = self.translate_with_lang(&fallback::dst_text(&first), pivot, to)?;
+                Ok(fallback::merge(first, second.into(), route))
+            }
+        }
+    }
+
+    /// 使用指定的语言方向进行通用翻译，不修改客户端配置
+    #[cfg(feature = "domain")]
+    fn translate_with_lang(&self, q: &str, from: Lang, to: Lang) -> anyhow::Result<CommonResult> {
+        let params = self.translate_form(q, from, to);
+
+        Ok(self
+            .send(self.http_client.post(COMMON_URL).form(&params))?
+            .data)
+    }
+
+    /// 使用指定的语言方向进行垂直领域翻译，不修改客户端配置
+    #[cfg(feature = "domain")]
+    fn domain_translate_with_lang(
+        &self,
+        q: &str,
+        domain: crate::domain::Domain,
+        from: Lang,
+        to: Lang,
     ) -> anyhow::Result<crate::domain::model::DomainResult> {
         use crate::constant::DOMAIN_URL;
 
-        let params = util::build_domain_form(&self.config.borrow(), q, domain);
+        let params = self.domain_form(q, domain, from, to);
+
+        Ok(self
+            .send(self.http_client.post(DOMAIN_URL).form(&params))?
+            .data)
+    }
+
+    /// 构建垂直领域翻译表单，语言方向由参数指定
+    #[cfg(feature = "domain")]
+    fn domain_form(
+        &self,
+        q: &str,
+        domain: crate::domain::Domain,
+        from: Lang,
+        to: Lang,
+    ) -> std::collections::HashMap<String, String> {
+        util::build_domain_form(&self.config.borrow(), q, domain, from, to)
+    }
+
+    /// 语音翻译
+    /// - request: 语音翻译请求，需要指定源语言和目标语言
+    #[cfg(feature = "voice")]
+    pub fn voice_translate(
+        &self,
+        request: &crate::voice::VoiceRequest,
+    ) -> anyhow::Result<crate::voice::model::VoiceResult> {
+        Ok(self.voice_translate_raw(request)?.data)
+    }
+
+    /// 语音翻译，返回原始响应信息
+    /// - request: 语音翻译请求，需要指定源语言和目标语言
+    #[cfg(feature = "voice")]
+    pub fn voice_translate_raw(
+        &self,
+        request: &crate::voice::VoiceRequest,
+    ) -> anyhow::Result<Response<crate::voice::model::VoiceResult>> {
+        use crate::constant::VOICE_URL;
+
+        let (headers, body) = util::build_voice_request(&self.config.borrow(), request)?;
+        let mut builder = self.http_client.post(VOICE_URL).json(&body);
+        for (k, v) in headers {
+            builder = builder.header(k, v);
+        }
+
+        self.send(builder)
+    }
+
+    /// 语音翻译，根据扩展名推断语音格式，WAV从文件头读取采样率，其他格式按16000Hz
+    /// - path: 语音文件路径
+    #[cfg(feature = "voice")]
+    pub fn voice_translate_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<crate::voice::model::VoiceResult> {
+        Ok(self.voice_translate_file_raw(path)?.data)
+    }
+
+    /// 语音翻译，根据扩展名推断语音格式，返回原始响应信息
+    /// - path: 语音文件路径
+    #[cfg(feature = "voice")]
+    pub fn voice_translate_file_raw(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<Response<crate::voice::model::VoiceResult>> {
+        use crate::voice::{VoiceFormat, VoiceRequest};
+
+        let path = path.as_ref();
+        let format = VoiceFormat::from_name(path)
+            .ok_or_else(|| anyhow::anyhow!("无法识别的语音格式: {}", path.display()))?;
+        let data = fs::read(path)?;
 
-        let resp = self.http_client.post(DOMAIN_URL).form(&params).send()?;
-        Ok(resp.json()?)
+        self.voice_translate_raw(&VoiceRequest::new(format, data))
     }
 
     /// 文档翻译统计校验服务
     /// - `data`: 文件数据
     /// - `name`: 文件名
-    /// - `ext`: 文件扩展名
+    /// - `format`: 文档格式
     #[cfg(feature = "doc")]
     pub fn doc_count_translate(
         &self,
         data: Vec<u8>,
         name: &str,
-        ext: &str,
+        format: crate::document::format::DocFormat,
     ) -> anyhow::Result<crate::document::model::DocCountResult> {
-        use crate::constant::DOC_COUNT_URL;
+        Ok(self.doc_count_translate_raw(data, name, format)?.data)
+    }
+
+    /// 文档翻译统计校验服务，返回原始响应信息
+    /// - `data`: 文件数据
+    /// - `name`: 文件名
+    /// - `format`: 文档格式
+    #[cfg(feature = "doc")]
+    pub fn doc_count_translate_raw(
+        &self,
+        data: Vec<u8>,
+        name: &str,
+        format: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocCountResult>> {
+        let file_md5 = util::md5_hex(&data);
+        let part = reqwest::blocking::multipart::Part::bytes(data).file_name(name.to_string());
+        self.doc_count_part(part, &file_md5, format)
+    }
+
+    /// 文档翻译统计校验服务，从文件中流式上传，根据文件头和扩展名推断文档格式
+    /// - `path`: 文档路径
+    #[cfg(feature = "doc")]
+    pub fn doc_count_translate_file(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<crate::document::model::DocCountResult> {
+        Ok(self.doc_count_translate_file_raw(path)?.data)
+    }
+
+    /// 文档翻译统计校验服务，从文件中流式上传，返回原始响应信息
+    /// - `path`: 文档路径
+    #[cfg(feature = "doc")]
+    pub fn doc_count_translate_file_raw(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> anyhow::Result<Response<crate::document::model::DocCountResult>> {
+        let (file, name, format) = self.open_document(path.as_ref())?;
+        self.doc_count_translate_reader_raw(&name, file, format)
+    }
+
+    /// 文档翻译统计校验服务，从reader中流式上传
+    /// - `name`: 文件名
+    /// - `reader`: 文件数据
+    /// - `format`: 文档格式
+    #[cfg(feature = "doc")]
+    pub fn doc_count_translate_reader<R>(
+        &self,
+        name: &str,
+        reader: R,
+        format: crate::document::format::DocFormat,
+    ) -> anyhow::Result<crate::document::model::DocCountResult>
+    where
+        R: std::io::Read + std::io::Seek + Send + 'static,
+    {
+        Ok(self
+            .doc_count_translate_reader_raw(name, reader, format)?
+            .data)
+    }
+
+    /// 文档翻译统计校验服务，从reader中流式上传，返回原始响应信息
+    /// - `name`: 文件名
+    /// - `reader`: 文件数据
+    /// - `format`: 文档格式
+    #[cfg(feature = "doc")]
+    pub fn doc_count_translate_reader_raw<R>(
+        &self,
+        name: &str,
+        reader: R,
+        format: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocCountResult>>
+    where
+        R: std::io::Read + std::io::Seek + Send + 'static,
+    {
+        let (part, file_md5) = util::reader_part_blocking(name, reader)?;
+        self.doc_count_part(part, &file_md5, format)
+    }
 
-        let params = util::build_doc_count_form_blocking(&self.config.borrow(), data, name, ext)?;
+    #[cfg(feature = "doc")]
+    fn doc_count_part(
+        &self,
+        part: reqwest::blocking::multipart::Part,
+        file_md5: &str,
+        format: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocCountResult>> {
+        use crate::constant::DOC_COUNT_URL;
 
-        let resp = self
-            .http_client
-            .post(DOC_COUNT_URL)
-            .multipart(params)
-            .send()?;
+        let params =
+            util::build_doc_count_form_blocking(&self.config.borrow(), part, file_md5, format);
 
-        Ok(resp.json()?)
+        self.send(self.http_client.post(DOC_COUNT_URL).multipart(params))
     }
 
     /// 文档翻译服务
     /// - `data`: 文件数据
     /// - `name`: 文件名
-    /// - `ext`: 文件扩展名
-    /// - `out_type`: 输出扩展名
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
     #[cfg(feature = "doc")]
     pub fn doc_translate(
         &self,
         data: Vec<u8>,
         name: &str,
-        ext: &str,
-        out_type: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<crate::document::model::DocResult> {
+        Ok(self.doc_translate_raw(data, name, format, out)?.data)
+    }
+
+    /// 文档翻译服务，返回原始响应信息
+    /// - `data`: 文件数据
+    /// - `name`: 文件名
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
+    #[cfg(feature = "doc")]
+    pub fn doc_translate_raw(
+        &self,
+        data: Vec<u8>,
+        name: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
+        format.check_conversion(out)?;
+
+        let file_md5 = util::md5_hex(&data);
+        let part = reqwest::blocking::multipart::Part::bytes(data).file_name(name.to_string());
+        self.doc_translate_part(part, &file_md5, format, out, None)
+    }
+
+    /// 文档翻译服务，从文件中流式上传，根据文件头和扩展名推断文档格式
+    /// - `path`: 文档路径
+    /// - `out`: 输出格式
+    #[cfg(feature = "doc")]
+    pub fn doc_translate_file(
+        &self,
+        path: impl AsRef<Path>,
+        out: crate::document::format::DocFormat,
     ) -> anyhow::Result<crate::document::model::DocResult> {
+        Ok(self.doc_translate_file_raw(path, out)?.data)
+    }
+
+    /// 文档翻译服务，从文件中流式上传，返回原始响应信息
+    /// - `path`: 文档路径
+    /// - `out`: 输出格式
+    #[cfg(feature = "doc")]
+    pub fn doc_translate_file_raw(
+        &self,
+        path: impl AsRef<Path>,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
+        self.doc_translate_file_request(path.as_ref(), out, None)
+    }
+
+    /// 文档翻译服务，从reader中流式上传
+    /// - `name`: 文件名
+    /// - `reader`: 文件数据
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
+    #[cfg(feature = "doc")]
+    pub fn doc_translate_reader<R>(
+        &self,
+        name: &str,
+        reader: R,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<crate::document::model::DocResult>
+    where
+        R: std::io::Read + std::io::Seek + Send + 'static,
+    {
+        Ok(self
+            .doc_translate_reader_raw(name, reader, format, out)?
+            .data)
+    }
+
+    /// 文档翻译服务，从reader中流式上传，返回原始响应信息
+    /// - `name`: 文件名
+    /// - `reader`: 文件数据
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
+    #[cfg(feature = "doc")]
+    pub fn doc_translate_reader_raw<R>(
+        &self,
+        name: &str,
+        reader: R,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>>
+    where
+        R: std::io::Read + std::io::Seek + Send + 'static,
+    {
+        format.check_conversion(out)?;
+
+        let (part, file_md5) = util::reader_part_blocking(name, reader)?;
+        self.doc_translate_part(part, &file_md5, format, out, None)
+    }
+
+    #[cfg(feature = "doc")]
+    fn doc_translate_file_request(
+        &self,
+        path: &Path,
+        out: crate::document::format::DocFormat,
+        file_id: Option<&str>,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
+        let (file, name, format) = self.open_document(path)?;
+        format.check_conversion(out)?;
+
+        let (part, file_md5) = util::reader_part_blocking(&name, file)?;
+        self.doc_translate_part(part, &file_md5, format, out, file_id)
+    }
+
+    #[cfg(feature = "doc")]
+    fn doc_translate_part(
+        &self,
+        part: reqwest::blocking::multipart::Part,
+        file_md5: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+        file_id: Option<&str>,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
         use crate::constant::DOC_URL;
 
-        let params =
-            util::build_doc_form_blocking(&self.config.borrow(), data, name, ext, out_type)?;
+        let params = util::build_doc_form_blocking(
+            &self.config.borrow(),
+            part,
+            file_md5,
+            format,
+            out,
+            file_id,
+        );
+
+        self.send(self.http_client.post(DOC_URL).multipart(params))
+    }
+
+    /// 先统计报价，确认后再提交文档翻译
+    ///
+    /// 报价未通过确认时返回[`QuoteRejected`](crate::document::quote::QuoteRejected)错误，不会提交翻译。
+    /// 提交翻译时附带统计接口返回的`fileId`
+    /// - `data`: 文件数据
+    /// - `name`: 文件名
+    /// - `format`: 文档格式
+    /// - `out`: 输出格式，需要是`format`支持的转换
+    /// - `approve`: 确认报价，返回`true`时提交翻译，可以使用[`max_amount`](crate::document::quote::max_amount)
+    #[cfg(feature = "doc")]
+    pub fn doc_translate_quoted(
+        &self,
+        data: Vec<u8>,
+        name: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+        approve: impl FnOnce(&crate::document::model::DocCountData) -> bool,
+    ) -> anyhow::Result<crate::document::model::DocResult> {
+        Ok(self
+            .doc_translate_quoted_raw(data, name, format, out, approve)?
+            .data)
+    }
+
+    /// 先统计报价，确认后再提交文档翻译，返回提交翻译的原始响应信息
+    ///
+    /// 参数同[`doc_translate_quoted`](Self::doc_translate_quoted)
+    #[cfg(feature = "doc")]
+    pub fn doc_translate_quoted_raw(
+        &self,
+        data: Vec<u8>,
+        name: &str,
+        format: crate::document::format::DocFormat,
+        out: crate::document::format::DocFormat,
+        approve: impl FnOnce(&crate::document::model::DocCountData) -> bool,
+    ) -> anyhow::Result<Response<crate::document::model::DocResult>> {
+        use crate::document::quote;
+
+        format.check_conversion(out)?;
+
+        // 统计和翻译读取同一份数据，避免在内存中保存两份文档
+        let data = util::SharedBuf::new(data);
+        let count = self.doc_count_translate_reader(name, data.reader(), format)?;
+        let quote = quote::approve(count, approve)?;
+
+        let file_id = Some(quote.file_id.as_str()).filter(|id| !id.is_empty());
+        let (part, file_md5) = util::reader_part_blocking(name, data.reader())?;
+        self.doc_translate_part(part, &file_md5, format, out, file_id)
+    }
+
+    /// 查询文档翻译任务状态
+    /// - `request_id`: 文档翻译返回的请求id
+    #[cfg(feature = "doc")]
+    pub fn doc_query(
+        &self,
+        request_id: &str,
+    ) -> anyhow::Result<crate::document::model::DocQueryResult> {
+        Ok(self.doc_query_raw(request_id)?.data)
+    }
+
+    /// 查询文档翻译任务状态，返回原始响应信息
+    /// - `request_id`: 文档翻译返回的请求id
+    #[cfg(feature = "doc")]
+    pub fn doc_query_raw(
+        &self,
+        request_id: &str,
+    ) -> anyhow::Result<Response<crate::document::model::DocQueryResult>> {
+        use crate::constant::DOC_QUERY_URL;
+
+        let params = util::build_doc_query_form(&self.config.borrow(), request_id);
+
+        self.send(self.http_client.post(DOC_QUERY_URL).form(&params))
+    }
+
+    /// 翻译文档并下载译文
+    ///
+    /// 从文件中流式上传文档，按[`PollOptions`](crate::document::job::PollOptions)查询任务状态，
+    /// 成功后下载译文到[`output_path`](crate::document::job::output_path)，返回译文路径。
+    /// 目标语言为自动时在提交前返回错误
+    /// - `path`: 文档路径，根据文件头和扩展名推断文档格式
+    /// - `out`: 输出格式
+    #[cfg(feature = "doc")]
+    pub fn translate_document(
+        &self,
+        path: impl AsRef<Path>,
+        out: crate::document::format::DocFormat,
+    ) -> anyhow::Result<std::path::PathBuf> {
+        let path = path.as_ref();
+        let output = crate::document::job::output_path(path, self.langs().1, out)?;
+        let result = self.doc_translate_file(path, out)?;
+        self.doc_wait_download(result, output)
+    }
+
+    /// 翻译文档并下载译文，提交前先统计报价并确认
+    ///
+    /// 报价未通过确认时返回[`QuoteRejected`](crate::document::quote::QuoteRejected)错误
+    /// - `path`: 文档路径，根据文件头和扩展名推断文档格式
+    /// - `out`: 输出格式
+    /// - `approve`: 确认报价，返回`true`时提交翻译
+    #[cfg(feature = "doc")]
+    pub fn translate_document_quoted(
+        &self,
+        path: impl AsRef<Path>,
+        out: crate::document::format::DocFormat,
+        approve: impl FnOnce(&crate::document::model::DocCountData) -> bool,
+    ) -> anyhow::Result<std::path::PathBuf> {
+        use crate::document::quote;
+
+        let path = path.as_ref();
+        let output = crate::document::job::output_path(path, self.langs().1, out)?;
+        let (file, name, format) = self.open_document(path)?;
+        format.check_conversion(out)?;
+
+        let count = self.doc_count_translate_reader(&name, file, format)?;
+        let quote = quote::approve(count, approve)?;
+
+        let file_id = Some(quote.file_id.as_str()).filter(|id| !id.is_empty());
+        let result = self.doc_translate_file_request(path, out, file_id)?.data;
+        self.doc_wait_download(result, output)
+    }
+
+    /// 等待文档翻译完成并下载译文
+    #[cfg(feature = "doc")]
+    fn doc_wait_download(
+        &self,
+        result: crate::document::model::DocResult,
+        output: std::path::PathBuf,
+    ) -> anyhow::Result<std::path::PathBuf> {
+        use crate::document::job;
+
+        let request_id = match result.data {
+            Some(data) if result.error_code.is_success() => data.request_id,
+            _ => anyhow::bail!("{}: {}", result.error_code, result.error_msg),
+        };
+
+        let options = self.config.borrow().doc_poll;
+        let start = Instant::now();
+        let mut interval = options.initial_interval;
+        let url = loop {
+            std::thread::sleep(interval);
+
+            let result = self.doc_query(&request_id)?;
+            if !result.error_code.is_success() {
+                anyhow::bail!("{}: {}", result.error_code, result.error_msg);
+            }
+            if let Some(data) = result.data.filter(|data| data.status.is_finished()) {
+                break job::finished_url(&data)?.to_string();
+            }
+
+            if options
+                .timeout
+                .is_some_and(|timeout| start.elapsed() >= timeout)
+            {
+                anyhow::bail!("文档翻译超时: {}", request_id);
+            }
+            interval = options.next_interval(interval);
+        };
+
+        let resp = self.http_client.get(url).send()?.error_for_status()?;
+        let data = resp.bytes()?;
+        fs::write(&output, data)?;
+
+        Ok(output)
+    }
+
+    /// 打开文档，根据文件头和扩展名推断文档格式
+    #[cfg(feature = "doc")]
+    fn open_document(
+        &self,
+        path: &Path,
+    ) -> anyhow::Result<(fs::File, String, crate::document::format::DocFormat)> {
+        use std::io::{Read, Seek, SeekFrom};
+
+        use crate::document::job;
 
-        let resp = self.http_client.post(DOC_URL).multipart(params).send()?;
+        let mut file = fs::File::open(path)?;
+        let mut head = Vec::new();
+        file.by_ref().take(job::SNIFF_LEN).read_to_end(&mut head)?;
+        file.seek(SeekFrom::Start(0))?;
 
-        Ok(resp.json()?)
+        let (name, format) = job::name_and_format(path, &head)?;
+        Ok((file, name, format))
     }
 }
@@ -1,7 +1,12 @@
 //! 客户端配置
+use std::path::PathBuf;
+
 use crate::lang::Lang;
 
 /// 客户端配置
+///
+/// 部分字段只在开启对应feature时存在，需要通过[`Config::new`]创建
+#[non_exhaustive]
 pub struct Config {
     /// APP ID
     pub app_id: String,
@@ -17,6 +22,17 @@ pub struct Config {
     pub open_tts: bool,
     /// 是否开通了"我的术语"
     pub open_action: bool,
+    /// TTS音频缓存目录，设置后按文本和语种缓存下载的音频
+    pub tts_cache_dir: Option<PathBuf>,
+    /// 图片翻译请求参数
+    #[cfg(feature = "image")]
+    pub image: crate::image::ImageRequest,
+    /// 文档翻译任务的轮询参数
+    #[cfg(feature = "doc")]
+    pub doc_poll: crate::document::job::PollOptions,
+    /// 文档翻译完成后的回调地址
+    #[cfg(feature = "doc")]
+    pub doc_callback: Option<String>,
 }
 
 impl Config {
@@ -30,6 +46,13 @@ impl Config {
             open_dict: false,
             open_tts: false,
             open_action: false,
+            tts_cache_dir: None,
+            #[cfg(feature = "image")]
+            image: Default::default(),
+            #[cfg(feature = "doc")]
+            doc_poll: Default::default(),
+            #[cfg(feature = "doc")]
+            doc_callback: None,
         }
     }
 
@@ -42,4 +65,15 @@ impl Config {
     pub fn set_to(&mut self, to: Lang) {
         self.to = to;
     }
+
+    /// 设置TTS音频缓存目录
+    pub fn set_tts_cache_dir(&mut self, dir: impl Into<PathBuf>) {
+        self.tts_cache_dir = Some(dir.into());
+    }
+
+    /// 设置文档翻译完成后的回调地址
+    #[cfg(feature = "doc")]
+    pub fn set_doc_callback(&mut self, url: impl Into<String>) {
+        self.doc_callback = Some(url.into());
+    }
 }
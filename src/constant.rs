@@ -1,12 +1,22 @@
 //! 相关常量
 
 /// 百度通用翻译API地址
-pub const COMMON_URL: &'static str = "https://fanyi-api.baidu.com/api/trans/vip/translate";
+pub const COMMON_URL: &str = "https://fanyi-api.baidu.com/api/trans/vip/translate";
 /// 图片翻译API地址
-pub const IMAGE_URL: &'static str = "https://fanyi-api.baidu.com/api/trans/sdk/picture";
+pub const IMAGE_URL: &str = "https://fanyi-api.baidu.com/api/trans/sdk/picture";
 /// 垂直领域翻译API地址
-pub const DOMAIN_URL: &'static str = "https://fanyi-api.baidu.com/api/trans/vip/fieldtranslate";
+pub const DOMAIN_URL: &str = "https://fanyi-api.baidu.com/api/trans/vip/fieldtranslate";
 /// 文档翻译统计校验API地址
-pub const DOC_COUNT_URL: &'static str = "https://fanyi-api.baidu.com/api/trans/vip/doccount";
+pub const DOC_COUNT_URL: &str = "https://fanyi-api.baidu.com/api/trans/vip/doccount";
 /// 文档翻译API地址
-pub const DOC_URL: &'static str = "https://fanyi-api.baidu.com/api/trans/vip/doctrans";
+pub const DOC_URL: &str = "https://fanyi-api.baidu.com/api/trans/vip/doctrans";
+/// 文档翻译查询API地址
+pub const DOC_QUERY_URL: &str = "https://fanyi-api.baidu.com/api/trans/vip/docquery";
+/// 语音翻译API地址
+pub const VOICE_URL: &str = "https://fanyi-api.baidu.com/api/trans/v2/voicetrans";
+/// 大模型文本翻译API地址
+pub const LLM_URL: &str = "https://fanyi-api.baidu.com/ait/api/aiTextTranslate";
+/// 百度智能云access token地址
+pub const BCE_TOKEN_URL: &str = "https://aip.baidubce.com/oauth/2.0/token";
+/// 百度智能云文本翻译API地址
+pub const BCE_TEXT_URL: &str = "https://aip.baidubce.com/rpc/2.0/mt/texttrans/v1";
@@ -0,0 +1,292 @@
+//! 批量翻译目录中的文档
+//!
+//! 先对每个文档调用统计接口报价，确认后再提交翻译，译文按原目录结构保存到输出目录。
+//! 每个文档的报价、请求id和状态记录在输出目录的清单文件中，中断后再次运行会从清单继续：
+//! 已下载的文档跳过，已提交的文档继续查询，文件内容变化后重新报价。
+//!
+//! 需要开启`batch`和`doc` features，只支持异步客户端
+//!
+//! ```toml
+//! baidu_trans = { version = "0.8.0", features = ["batch", "doc"] }
+//! ```
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{format::DocFormat, model::DocCountData};
+
+/// 清单文件名
+pub const MANIFEST_NAME: &str = ".baidu_trans_docs.json";
+
+/// 批量翻译参数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocBatchOptions {
+    /// 包含的文件，glob模式，匹配相对于目录的路径，默认为Word、Excel、PowerPoint和PDF文档
+    pub include: Vec<String>,
+    /// 排除的文件，glob模式，匹配相对于目录的路径
+    pub exclude: Vec<String>,
+    /// 是否遍历子目录，默认是
+    pub recursive: bool,
+    /// 输出格式，为空或文档不支持转换为该格式时，使用文档格式支持的第一个输出格式
+    pub out: Option<DocFormat>,
+}
+
+impl Default for DocBatchOptions {
+    fn default() -> Self {
+        Self {
+            include: [
+                "*.docx", "*.doc", "*.wps", "*.xlsx", "*.xls", "*.pptx", "*.ppt", "*.pdf",
+            ]
+            .map(String::from)
+            .to_vec(),
+            exclude: Vec::new(),
+            recursive: true,
+            out: None,
+        }
+    }
+}
+
+/// 文档在批量翻译中的状态
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "state", content = "reason", rename_all = "snake_case")]
+pub enum JobState {
+    /// 等待报价
+    #[default]
+    Pending,
+    /// 已报价，等待提交
+    Quoted,
+    /// 报价未通过确认
+    Declined,
+    /// 已提交，等待翻译完成
+    Submitted,
+    /// 译文已下载
+    Downloaded,
+    /// 失败
+    Failed(String),
+}
+
+/// 清单中的文档记录
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// 文档格式
+    pub format: String,
+    /// 输出格式
+    pub out: String,
+    /// 文件大小，用于判断文件是否变化
+    pub size: u64,
+    /// 文件修改时间(秒)，用于判断文件是否变化
+    pub modified: u64,
+    /// 总字符数
+    pub char_count: usize,
+    /// 消费金额，单位：分
+    pub amount: usize,
+    /// 统计接口返回的文件id
+    pub file_id: String,
+    /// 文档翻译请求id
+    pub request_id: String,
+    /// 状态
+    #[serde(flatten)]
+    pub state: JobState,
+}
+
+/// 批量翻译清单，key为文档相对于输入目录的路径(以`/`分隔)
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    /// 源语言
+    pub from: String,
+    /// 目标语言
+    pub to: String,
+    /// 文档记录
+    pub entries: BTreeMap<String, ManifestEntry>,
+}
+
+impl Manifest {
+    /// 读取清单，不存在时返回空清单
+    pub async fn load(path: &Path) -> anyhow::Result<Self> {
+        match tokio::fs::read(path).await {
+            Ok(data) => Ok(serde_json::from_slice(&data)?),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// 保存清单，先写入临时文件再替换，避免中断时清单损坏
+    pub async fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let tmp = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, serde_json::to_vec_pretty(self)?).await?;
+        tokio::fs::rename(&tmp, path).await?;
+        Ok(())
+    }
+
+    /// 语言方向变化时清空记录
+    pub(crate) fn reset_lang(&mut self, from: &str, to: &str) {
+        if self.from != from || self.to != to {
+            self.entries.clear();
+            self.from = from.to_string();
+            self.to = to.to_string();
+        }
+    }
+
+    /// 同步目录中的文档，文件变化、输出格式变化或失败的记录重新报价，已不存在的文档移除
+    pub(crate) fn sync(&mut self, files: Vec<(String, ManifestEntry)>) {
+        let mut entries = BTreeMap::new();
+        for (key, file) in files {
+            let entry = match self.entries.remove(&key) {
+                Some(entry)
+                    if entry.size == file.size
+                        && entry.modified == file.modified
+                        && entry.out == file.out
+                        && !matches!(entry.state, JobState::Failed(_)) =>
+                {
+                    entry
+                }
+                _ => file,
+            };
+            entries.insert(key, entry);
+        }
+        self.entries = entries;
+    }
+
+    /// 报价汇总，包含已报价未提交的文档
+    pub fn quote(&self) -> DirQuote {
+        let files: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| matches!(entry.state, JobState::Quoted | JobState::Declined))
+            .map(|(key, entry)| (key.clone(), entry.quote()))
+            .collect();
+
+        DirQuote {
+            char_count: files.iter().map(|(_, q)| q.char_count).sum(),
+            amount: files.iter().map(|(_, q)| q.amount).sum(),
+            files,
+        }
+    }
+}
+
+impl ManifestEntry {
+    /// 报价信息
+    pub fn quote(&self) -> DocCountData {
+        DocCountData {
+            char_count: self.char_count,
+            file_id: self.file_id.clone(),
+            amount: self.amount,
+            ..Default::default()
+        }
+    }
+
+    /// 文档格式和输出格式
+    pub(crate) fn formats(&self) -> anyhow::Result<(DocFormat, DocFormat)> {
+        let parse = |s: &str| s.parse::<DocFormat>().map_err(anyhow::Error::msg);
+        Ok((parse(&self.format)?, parse(&self.out)?))
+    }
+}
+
+/// 目录报价汇总
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DirQuote {
+    /// 每个文档的报价，路径相对于输入目录
+    pub files: Vec<(String, DocCountData)>,
+    /// 总字符数
+    pub char_count: usize,
+    /// 总金额，单位：分
+    pub amount: usize,
+}
+
+impl Display for DirQuote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}个文档，{}字符，{}.{:02}元",
+            self.files.len(),
+            self.char_count,
+            self.amount / 100,
+            self.amount % 100
+        )
+    }
+}
+
+/// 批量翻译结果
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DocBatchReport {
+    /// 报价汇总
+    pub quote: DirQuote,
+    /// 本次下载的译文
+    pub downloaded: Vec<PathBuf>,
+    /// 报价未通过确认的文档
+    pub declined: Vec<String>,
+    /// 失败的文档及原因
+    pub failed: Vec<(String, String)>,
+    /// 超时仍在翻译中的文档，再次运行时继续查询
+    pub pending: Vec<String>,
+}
+
+/// 清单文件路径
+pub fn manifest_path(out_dir: &Path) -> PathBuf {
+    out_dir.join(MANIFEST_NAME)
+}
+
+/// 译文保存路径，按相对路径保存到输出目录，保留原扩展名并追加输出格式的扩展名，
+/// 如`a.pdf`保存为`a.pdf.docx`，避免同名不同格式的文档互相覆盖
+pub fn mirror_path(out_dir: &Path, key: &str, out: DocFormat) -> PathBuf {
+    out_dir.join(format!("{}.{}", key, out.extension()))
+}
+
+/// 遍历目录，返回相对路径和新的清单记录
+///
+/// 输出目录位于输入目录中时跳过输出目录，避免译文被再次报价
+pub(crate) fn collect(
+    dir: &Path,
+    out_dir: &Path,
+    options: &DocBatchOptions,
+) -> anyhow::Result<Vec<(String, ManifestEntry)>> {
+    let files =
+        crate::util::walk_files(dir, &options.include, &options.exclude, options.recursive)?;
+    let out_dir = out_dir.canonicalize().ok();
+
+    files
+        .into_iter()
+        .filter(|path| {
+            !out_dir.as_ref().is_some_and(|out_dir| {
+                path.canonicalize()
+                    .is_ok_and(|path| path.starts_with(out_dir))
+            })
+        })
+        .map(|path| {
+            let relative = path.strip_prefix(dir).unwrap_or(&path);
+            let key = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            let format = DocFormat::from_name(&path)
+                .ok_or_else(|| anyhow::anyhow!("无法识别的文档格式: {}", path.display()))?;
+            let out = options
+                .out
+                .filter(|out| format.can_convert_to(*out))
+                .unwrap_or(format.outputs()[0]);
+
+            let metadata = std::fs::metadata(&path)?;
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_secs());
+
+            let entry = ManifestEntry {
+                format: format.to_string(),
+                out: out.to_string(),
+                size: metadata.len(),
+                modified,
+                ..Default::default()
+            };
+            Ok((key, entry))
+        })
+        .collect()
+}
@@ -0,0 +1,65 @@
+//! axum适配
+//!
+//! [`DocNotification`]实现了[`FromRequest`]，在路由状态中提供[`CallbackHandler`]即可直接提取：
+//!
+//! ```no_run
+//! use axum::{routing::post, Router};
+//! use baidu_trans::document::callback::{axum::Ack, CallbackHandler, DocNotification};
+//!
+//! async fn notify(notification: DocNotification) -> Ack {
+//!     println!("{} {}", notification.request_id, notification.status);
+//!     Ack
+//! }
+//!
+//! let app: Router = Router::new()
+//!     .route("/baidu/doc", post(notify))
+//!     .with_state(CallbackHandler::new("app_id", "secret_key"));
+//! ```
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{FromRef, FromRequest, Request},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+use super::{CallbackError, CallbackHandler, DocNotification, ACK_BODY};
+
+#[async_trait]
+impl<S> FromRequest<S> for DocNotification
+where
+    CallbackHandler: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = CallbackError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(|e| CallbackError::Malformed(e.to_string()))?;
+
+        CallbackHandler::from_ref(state).handle(content_type.as_deref(), &body)
+    }
+}
+
+impl IntoResponse for CallbackError {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status()).unwrap_or(StatusCode::BAD_REQUEST);
+        (status, self.to_string()).into_response()
+    }
+}
+
+/// 通知处理成功的响应
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Ack;
+
+impl IntoResponse for Ack {
+    fn into_response(self) -> Response {
+        ([(header::CONTENT_TYPE, "application/json")], ACK_BODY).into_response()
+    }
+}
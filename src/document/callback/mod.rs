@@ -0,0 +1,168 @@
+//! 文档翻译完成回调
+//!
+//! 通过[`Config::set_doc_callback`](crate::config::Config::set_doc_callback)设置回调地址后，
+//! 文档翻译完成时百度会向该地址发送通知。[`CallbackHandler`]不依赖具体的web框架，
+//! 负责解析通知、校验签名并返回[`DocNotification`]。
+//!
+//! 需要开启`callback` feature，开启`axum` feature时提供axum的提取器
+//!
+//! ```toml
+//! baidu_trans = { version = "0.8.0", features = ["callback"] }
+//! ```
+#[cfg(feature = "axum")]
+pub mod axum;
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use serde::Deserialize;
+
+use super::model::DocStatus;
+use crate::config::Config;
+
+/// 通知处理成功后返回给百度的响应内容
+pub const ACK_BODY: &str = r#"{"error_code":0,"error_msg":"success"}"#;
+
+/// 文档翻译完成通知
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct DocNotification {
+    /// 文档翻译请求id
+    pub request_id: String,
+    /// 任务状态
+    pub status: DocStatus,
+    /// 失败原因
+    #[serde(default)]
+    pub reason: String,
+    /// 译文下载链接
+    #[serde(default)]
+    pub file_url: String,
+    /// 源语言
+    #[serde(default)]
+    pub from: String,
+    /// 目标语言
+    #[serde(default)]
+    pub to: String,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 回调通知处理失败的原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackError {
+    /// 通知内容无法解析
+    Malformed(String),
+    /// 缺少签名
+    MissingSign,
+    /// 签名校验失败
+    InvalidSign,
+    /// 通知中的appid与客户端不一致
+    AppIdMismatch,
+}
+
+impl CallbackError {
+    /// 对应的HTTP状态码
+    pub fn status(&self) -> u16 {
+        match self {
+            CallbackError::Malformed(_) => 400,
+            CallbackError::MissingSign | CallbackError::InvalidSign => 401,
+            CallbackError::AppIdMismatch => 403,
+        }
+    }
+}
+
+impl Display for CallbackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallbackError::Malformed(msg) => write!(f, "无法解析回调通知: {}", msg),
+            CallbackError::MissingSign => f.write_str("回调通知缺少签名"),
+            CallbackError::InvalidSign => f.write_str("回调通知签名校验失败"),
+            CallbackError::AppIdMismatch => f.write_str("回调通知的appid与客户端不一致"),
+        }
+    }
+}
+
+impl std::error::Error for CallbackError {}
+
+/// 回调通知处理器
+///
+/// 通知可以是JSON或`application/x-www-form-urlencoded`格式，签名为除`sign`外
+/// 所有参数按key排序拼接成`k=v&`后加上密钥的md5
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallbackHandler {
+    app_id: String,
+    secret_key: String,
+}
+
+impl CallbackHandler {
+    /// 创建处理器
+    pub fn new(app_id: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            app_id: app_id.into(),
+            secret_key: secret_key.into(),
+        }
+    }
+
+    /// 解析并校验通知
+    /// - content_type: 请求的`Content-Type`，为空时根据内容判断格式
+    /// - body: 请求体
+    pub fn handle(
+        &self,
+        content_type: Option<&str>,
+        body: &[u8],
+    ) -> Result<DocNotification, CallbackError> {
+        let mut params = parse_params(content_type, body)?;
+
+        let sign = params.remove("sign").ok_or(CallbackError::MissingSign)?;
+        let expected = crate::util::doc_callback_sign(&params, &self.secret_key);
+        if !constant_time_eq(sign.to_lowercase().as_bytes(), expected.as_bytes()) {
+            return Err(CallbackError::InvalidSign);
+        }
+        if params.get("appid").is_some_and(|id| *id != self.app_id) {
+            return Err(CallbackError::AppIdMismatch);
+        }
+
+        let value = params
+            .into_iter()
+            .map(|(k, v)| (k, serde_json::Value::String(v)))
+            .collect::<serde_json::Map<_, _>>();
+        serde_json::from_value(value.into()).map_err(|e| CallbackError::Malformed(e.to_string()))
+    }
+}
+
+impl From<&Config> for CallbackHandler {
+    fn from(config: &Config) -> Self {
+        Self::new(&config.app_id, &config.secret_key)
+    }
+}
+
+/// 将通知解析为字符串参数，JSON中的非字符串值按原文参与签名
+fn parse_params(
+    content_type: Option<&str>,
+    body: &[u8],
+) -> Result<BTreeMap<String, String>, CallbackError> {
+    let is_json = match content_type {
+        Some(content_type) => content_type.contains("json"),
+        None => body.trim_ascii_start().starts_with(b"{"),
+    };
+
+    if !is_json {
+        return serde_urlencoded::from_bytes(body)
+            .map_err(|e| CallbackError::Malformed(e.to_string()));
+    }
+
+    let value: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_slice(body).map_err(|e| CallbackError::Malformed(e.to_string()))?;
+    Ok(value
+        .into_iter()
+        .map(|(k, v)| match v {
+            serde_json::Value::String(s) => (k, s),
+            v => (k, v.to_string()),
+        })
+        .collect())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
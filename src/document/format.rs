@@ -0,0 +1,168 @@
+//! 文档格式及支持的转换
+use std::{fmt::Display, path::Path, str::FromStr};
+
+/// 文档翻译支持的文档格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DocFormat {
+    /// Word 2007及以上
+    Docx,
+    /// Word 97-2003
+    Doc,
+    /// PDF
+    Pdf,
+    /// Excel 2007及以上
+    Xlsx,
+    /// Excel 97-2003
+    Xls,
+    /// PowerPoint 2007及以上
+    Pptx,
+    /// PowerPoint 97-2003
+    Ppt,
+    /// WPS文字
+    Wps,
+    /// 纯文本
+    Txt,
+    /// 网页
+    Html,
+}
+
+impl DocFormat {
+    /// 所有格式
+    pub const ALL: [DocFormat; 10] = [
+        DocFormat::Docx,
+        DocFormat::Doc,
+        DocFormat::Pdf,
+        DocFormat::Xlsx,
+        DocFormat::Xls,
+        DocFormat::Pptx,
+        DocFormat::Ppt,
+        DocFormat::Wps,
+        DocFormat::Txt,
+        DocFormat::Html,
+    ];
+
+    /// 扩展名，同时也是接口的`type`/`outPutType`参数
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DocFormat::Docx => "docx",
+            DocFormat::Doc => "doc",
+            DocFormat::Pdf => "pdf",
+            DocFormat::Xlsx => "xlsx",
+            DocFormat::Xls => "xls",
+            DocFormat::Pptx => "pptx",
+            DocFormat::Ppt => "ppt",
+            DocFormat::Wps => "wps",
+            DocFormat::Txt => "txt",
+            DocFormat::Html => "html",
+        }
+    }
+
+    /// 该格式可以输出的格式
+    ///
+    /// - Word类(doc/docx/wps)、pdf: docx、pdf
+    /// - Excel类: xlsx
+    /// - PowerPoint类: pptx
+    /// - txt: txt、docx
+    /// - html: html
+    pub fn outputs(&self) -> &'static [DocFormat] {
+        match self {
+            DocFormat::Docx | DocFormat::Doc | DocFormat::Wps | DocFormat::Pdf => {
+                &[DocFormat::Docx, DocFormat::Pdf]
+            }
+            DocFormat::Xlsx | DocFormat::Xls => &[DocFormat::Xlsx],
+            DocFormat::Pptx | DocFormat::Ppt => &[DocFormat::Pptx],
+            DocFormat::Txt => &[DocFormat::Txt, DocFormat::Docx],
+            DocFormat::Html => &[DocFormat::Html],
+        }
+    }
+
+    /// 是否可以转换为指定的输出格式
+    pub fn can_convert_to(&self, out: DocFormat) -> bool {
+        self.outputs().contains(&out)
+    }
+
+    /// 检查是否可以转换为指定的输出格式，不支持时返回错误
+    pub fn check_conversion(&self, out: DocFormat) -> anyhow::Result<()> {
+        if self.can_convert_to(out) {
+            return Ok(());
+        }
+
+        let outputs = self
+            .outputs()
+            .iter()
+            .map(|f| f.extension())
+            .collect::<Vec<_>>()
+            .join("/");
+        anyhow::bail!("{}文档不能输出为{}，支持的输出格式: {}", self, out, outputs)
+    }
+
+    /// 根据文件名的扩展名推断格式
+    pub fn from_name(name: impl AsRef<Path>) -> Option<DocFormat> {
+        name.as_ref().extension()?.to_str()?.parse().ok()
+    }
+
+    /// 根据文件头推断格式
+    ///
+    /// 97-2003格式和WPS共用同一种文件头，无法区分，返回`None`
+    pub fn from_bytes(data: &[u8]) -> Option<DocFormat> {
+        const OLE: &[u8] = &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+        if data.starts_with(b"%PDF-") {
+            return Some(DocFormat::Pdf);
+        }
+        if data.starts_with(OLE) {
+            return None;
+        }
+        if data.starts_with(b"PK\x03\x04") {
+            // OOXML压缩包中的目录名
+            return [
+                (&b"word/"[..], DocFormat::Docx),
+                (b"xl/", DocFormat::Xlsx),
+                (b"ppt/", DocFormat::Pptx),
+            ]
+            .into_iter()
+            .find(|(dir, _)| data.windows(dir.len()).any(|w| w == *dir))
+            .map(|(_, format)| format);
+        }
+
+        let head = String::from_utf8_lossy(&data[..data.len().min(512)]).to_lowercase();
+        let head = head.trim_start_matches('\u{feff}').trim_start();
+        if head.starts_with("<!doctype html") || head.starts_with("<html") {
+            return Some(DocFormat::Html);
+        }
+
+        None
+    }
+
+    /// 根据文件头和文件名推断格式，文件头可以确定格式时优先使用文件头
+    pub fn infer(name: impl AsRef<Path>, data: &[u8]) -> Option<DocFormat> {
+        DocFormat::from_bytes(data).or_else(|| DocFormat::from_name(name))
+    }
+}
+
+impl Display for DocFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.extension())
+    }
+}
+
+impl FromStr for DocFormat {
+    type Err = String;
+
+    /// 不区分大小写，`htm`解析为[`DocFormat::Html`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "docx" => Ok(DocFormat::Docx),
+            "doc" => Ok(DocFormat::Doc),
+            "pdf" => Ok(DocFormat::Pdf),
+            "xlsx" => Ok(DocFormat::Xlsx),
+            "xls" => Ok(DocFormat::Xls),
+            "pptx" => Ok(DocFormat::Pptx),
+            "ppt" => Ok(DocFormat::Ppt),
+            "wps" => Ok(DocFormat::Wps),
+            "txt" => Ok(DocFormat::Txt),
+            "html" | "htm" => Ok(DocFormat::Html),
+            _ => Err(format!("暂不支持的文档格式: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! 文档翻译任务的提交、查询和下载
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use super::{
+    format::DocFormat,
+    model::{DocQueryData, DocStatus},
+};
+use crate::lang::Lang;
+
+/// 查询任务状态的轮询参数
+///
+/// 每次查询后等待时间乘以`multiplier`，直到`max_interval`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PollOptions {
+    /// 首次查询前的等待时间，默认2秒
+    pub initial_interval: Duration,
+    /// 最长等待时间，默认30秒
+    pub max_interval: Duration,
+    /// 等待时间的增长倍数，默认1.5
+    pub multiplier: f64,
+    /// 整个任务的超时时间，默认30分钟，`None`表示不超时
+    pub timeout: Option<Duration>,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(30),
+            multiplier: 1.5,
+            timeout: Some(Duration::from_secs(30 * 60)),
+        }
+    }
+}
+
+impl PollOptions {
+    /// 下一次查询前的等待时间
+    pub(crate) fn next_interval(&self, interval: Duration) -> Duration {
+        interval
+            .mul_f64(self.multiplier.max(1.0))
+            .min(self.max_interval)
+    }
+}
+
+/// 译文保存路径，与原文件同目录，文件名为`<原文件名>.<目标语言>.<输出格式>`
+///
+/// 目标语言为[`Lang::Auto`]时返回错误
+pub fn output_path(path: &Path, to: Lang, out: DocFormat) -> anyhow::Result<PathBuf> {
+    if to == Lang::Auto {
+        anyhow::bail!("文档翻译需要指定目标语言");
+    }
+
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    Ok(path.with_file_name(format!("{}.{}.{}", stem, to, out)))
+}
+
+/// 根据结束状态返回下载链接，失败时返回错误
+pub(crate) fn finished_url(data: &DocQueryData) -> anyhow::Result<&str> {
+    match &data.status {
+        DocStatus::Succeeded if !data.file_url.is_empty() => Ok(&data.file_url),
+        DocStatus::Succeeded => anyhow::bail!("文档翻译成功但没有返回下载链接"),
+        status => anyhow::bail!("文档翻译{}: {}", status, data.reason),
+    }
+}
+
+/// 推断文档格式时读取的文件头长度
+pub(crate) const SNIFF_LEN: u64 = 8 * 1024;
+
+/// 取出文件名并根据文件头推断文档格式
+pub(crate) fn name_and_format(path: &Path, head: &[u8]) -> anyhow::Result<(String, DocFormat)> {
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| anyhow::anyhow!("无效的文件名: {}", path.display()))?;
+    let format = DocFormat::infer(name, head)
+        .ok_or_else(|| anyhow::anyhow!("无法识别的文档格式: {}", path.display()))?;
+
+    Ok((name.to_string(), format))
+}
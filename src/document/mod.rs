@@ -5,7 +5,14 @@
 //! 启动文档翻译需要开启`doc` feature
 //!
 //! ```toml
-//! baidu_trans = { version = "0.7.5", features = ["doc"] }
+//! baidu_trans = { version = "0.8.0", features = ["doc"] }
 //! ```
 
+#[cfg(feature = "batch")]
+pub mod batch;
+#[cfg(feature = "callback")]
+pub mod callback;
+pub mod format;
+pub mod job;
 pub mod model;
+pub mod quote;
@@ -1,21 +1,27 @@
 //! 文档翻译请求的返回结构相关模块
 
 use serde::Deserialize;
+
+use crate::model::ErrorCode;
+
 /// 统计服务返回结果
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct DocCountResult {
-    /// 错误码, 这里用serde_json::Value的原因是百度的接口调用成功后返回的是一个整形，
-    ///
-    /// 但是调用失败返回的确是一个字符串，无语死！
-    pub error_code: serde_json::Value,
+    /// 错误码，成功时返回整型，失败时返回字符串
+    pub error_code: ErrorCode,
     /// 错误消息
     pub error_msg: String,
     /// 结果数据
     pub data: Option<DocCountData>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 统计服务返回数据
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct DocCountData {
     /// 总字符数
@@ -24,25 +30,150 @@ pub struct DocCountData {
     pub file_id: String,
     /// 消费金额，单位：分
     pub amount: usize,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 文档翻译返回结果
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct DocResult {
-    /// 错误码, 这里用serde_json::Value的原因是百度的接口调用成功后返回的是一个整形，
-    ///
-    /// 但是调用失败返回的确是一个字符串，无语死！
-    pub error_code: serde_json::Value,
+    /// 错误码，成功时返回整型，失败时返回字符串
+    pub error_code: ErrorCode,
     /// 错误消息
     pub error_msg: String,
     /// 结果数据
     pub data: Option<DocData>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 文档翻译结果数据
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct DocData {
     /// 本次请求id
     pub request_id: String,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 文档翻译查询结果
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DocQueryResult {
+    /// 错误码，成功时返回整型，失败时返回字符串
+    pub error_code: ErrorCode,
+    /// 错误消息
+    pub error_msg: String,
+    /// 结果数据
+    pub data: Option<DocQueryData>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 文档翻译查询数据
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct DocQueryData {
+    /// 请求id
+    pub request_id: String,
+    /// 任务状态
+    pub status: DocStatus,
+    /// 失败原因
+    #[serde(default)]
+    pub reason: String,
+    /// 译文下载链接，任务成功后返回
+    #[serde(default)]
+    pub file_url: String,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 文档翻译任务状态
+#[derive(Debug, Clone, PartialEq, Default, Eq, Hash)]
+pub enum DocStatus {
+    /// 等待翻译
+    #[default]
+    NotStarted,
+    /// 翻译中
+    Running,
+    /// 翻译成功
+    Succeeded,
+    /// 翻译失败
+    Failed,
+    /// 译文已过期
+    Expired,
+    /// 未知状态
+    Other(String),
+}
+
+impl DocStatus {
+    /// 任务是否已经结束，结束后不需要继续查询
+    ///
+    /// 未知状态可能是百度新增的中间状态，按未结束处理
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            DocStatus::Succeeded | DocStatus::Failed | DocStatus::Expired
+        )
+    }
+
+    /// 状态字符串
+    pub fn as_str(&self) -> &str {
+        match self {
+            DocStatus::NotStarted => "NotStarted",
+            DocStatus::Running => "Running",
+            DocStatus::Succeeded => "Succeeded",
+            DocStatus::Failed => "Failed",
+            DocStatus::Expired => "Expired",
+            DocStatus::Other(status) => status,
+        }
+    }
+}
+
+impl std::fmt::Display for DocStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for DocStatus {
+    fn from(status: &str) -> Self {
+        match status {
+            "NotStarted" => DocStatus::NotStarted,
+            "Running" => DocStatus::Running,
+            "Succeeded" => DocStatus::Succeeded,
+            "Failed" => DocStatus::Failed,
+            "Expired" => DocStatus::Expired,
+            _ => DocStatus::Other(status.to_string()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DocStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let status = String::deserialize(deserializer)?;
+        Ok(status.as_str().into())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DocStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
@@ -0,0 +1,49 @@
+//! 先报价再翻译
+//!
+//! 文档翻译按字符数计费，提交翻译前先调用统计接口获取报价，
+//! 由调用方确认后再提交，避免误翻译大文件产生高额费用
+use std::fmt::Display;
+
+use super::model::{DocCountData, DocCountResult};
+
+/// 报价未通过确认，可以通过[`anyhow::Error::downcast_ref`]取出
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuoteRejected {
+    /// 统计接口返回的报价
+    pub quote: DocCountData,
+}
+
+impl Display for QuoteRejected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "文档翻译报价未通过确认: {}字符，{}.{:02}元",
+            self.quote.char_count,
+            self.quote.amount / 100,
+            self.quote.amount % 100
+        )
+    }
+}
+
+impl std::error::Error for QuoteRejected {}
+
+/// 消费金额不超过`limit`(单位：分)时通过
+pub fn max_amount(limit: usize) -> impl FnOnce(&DocCountData) -> bool {
+    move |quote| quote.amount <= limit
+}
+
+/// 检查统计结果并确认报价，返回通过确认的报价
+pub(crate) fn approve(
+    count: DocCountResult,
+    approve: impl FnOnce(&DocCountData) -> bool,
+) -> anyhow::Result<DocCountData> {
+    let quote = match count.data {
+        Some(quote) if count.error_code.is_success() => quote,
+        _ => anyhow::bail!("{}: {}", count.error_code, count.error_msg),
+    };
+    if !approve(&quote) {
+        return Err(QuoteRejected { quote }.into());
+    }
+
+    Ok(quote)
+}
@@ -0,0 +1,132 @@
+//! 垂直领域翻译不支持时回退到通用翻译
+use crate::{
+    lang::Lang,
+    model::{CommonResult, ErrorCode},
+};
+
+use super::{
+    model::{DomainResult, Route},
+    Domain,
+};
+
+/// 回退方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Fallback {
+    /// 直接使用通用翻译
+    #[default]
+    General,
+    /// 优先经过中文或英语中转，让其中一段仍然使用垂直领域翻译，
+    /// 无法中转时使用通用翻译
+    Pivot,
+}
+
+/// 翻译执行计划
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Plan {
+    /// 通用翻译
+    General,
+    /// 先通用翻译到中转语言，再领域翻译到目标语言
+    PivotBefore(Lang),
+    /// 先领域翻译到中转语言，再通用翻译到目标语言
+    PivotAfter(Lang),
+}
+
+impl Domain {
+    /// 是否支持指定的语言方向，[`Domain::Other`]或源语言为自动时无法判断，返回`None`
+    pub fn supports(&self, from: Lang, to: Lang) -> Option<bool> {
+        let both_ways = match self {
+            Domain::Electronics | Domain::Mechanics | Domain::Novel | Domain::Wiki => false,
+            Domain::Finance
+            | Domain::Medicine
+            | Domain::It
+            | Domain::Machinery
+            | Domain::Senimed
+            | Domain::Academic
+            | Domain::Aerospace
+            | Domain::News
+            | Domain::Law
+            | Domain::Contract => true,
+            Domain::Other(_) => return None,
+        };
+
+        match (from, to) {
+            (Lang::Auto, _) => None,
+            (Lang::Zh, Lang::En) => Some(true),
+            (Lang::En, Lang::Zh) => Some(both_ways),
+            _ => Some(false),
+        }
+    }
+}
+
+/// 接口返回的语言方向不支持错误
+pub(crate) fn is_unsupported(code: &ErrorCode) -> bool {
+    code == "58001"
+}
+
+/// 根据回退方式选择执行计划
+pub(crate) fn plan(domain: &Domain, from: Lang, to: Lang, fallback: Fallback) -> Plan {
+    if fallback == Fallback::General {
+        return Plan::General;
+    }
+
+    for pivot in [Lang::Zh, Lang::En] {
+        if pivot != from && pivot != to && domain.supports(pivot, to) == Some(true) {
+            return Plan::PivotBefore(pivot);
+        }
+        if pivot != from && pivot != to && domain.supports(from, pivot) == Some(true) {
+            return Plan::PivotAfter(pivot);
+        }
+    }
+
+    Plan::General
+}
+
+/// 翻译是否失败
+pub(crate) fn failed(result: &DomainResult) -> bool {
+    result
+        .error_code
+        .as_ref()
+        .is_some_and(|code| !code.is_success())
+}
+
+/// 拼接所有分段的译文，作为下一段翻译的原文
+pub(crate) fn dst_text(result: &DomainResult) -> String {
+    result
+        .trans_result
+        .iter()
+        .flatten()
+        .map(|t| t.dst.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 合并两段翻译结果，原文使用第一段的原文
+pub(crate) fn merge(first: DomainResult, mut second: DomainResult, route: Route) -> DomainResult {
+    if let (Some(first), Some(second)) = (&first.trans_result, &mut second.trans_result) {
+        if first.len() == second.len() {
+            for (a, b) in first.iter().zip(second.iter_mut()) {
+                b.src = a.src.clone();
+            }
+        }
+    }
+
+    DomainResult {
+        from: first.from,
+        route,
+        ..second
+    }
+}
+
+impl From<CommonResult> for DomainResult {
+    fn from(result: CommonResult) -> Self {
+        Self {
+            from: result.from,
+            to: result.to,
+            trans_result: result.trans_result,
+            error_code: result.error_code,
+            error_msg: result.error_msg,
+            route: Route::General,
+            extra: result.extra,
+        }
+    }
+}
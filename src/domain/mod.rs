@@ -1,9 +1,16 @@
 //! 垂直领域翻译相关模块
 
+pub mod fallback;
 pub mod model;
 use std::{fmt::Display, str::FromStr};
 
+use serde::{Deserialize, Deserializer};
+
 /// 垂直领域翻译支持范围
+///
+/// 各领域支持的语言方向以[百度翻译文档](https://fanyi-api.baidu.com/doc/22)为准，
+/// 接口新增的领域可以先通过[`Domain::Other`]使用
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Domain {
     /// 电子科技领域
     ///
@@ -15,15 +22,64 @@ pub enum Domain {
     /// - 英语-->中文
     Finance,
     /// 水利机械领域
+    ///
     /// - 中文-->英语
     Mechanics,
     /// 生物医药领域
+    ///
     /// - 中文-->英语
     /// - 英语-->中文
     Medicine,
     /// 网络文学领域
+    ///
     /// - 中文-->英语
     Novel,
+    /// 信息技术领域
+    ///
+    /// - 中文-->英语
+    /// - 英语-->中文
+    It,
+    /// 机械制造领域
+    ///
+    /// - 中文-->英语
+    /// - 英语-->中文
+    Machinery,
+    /// 生物医药领域(新版)
+    ///
+    /// - 中文-->英语
+    /// - 英语-->中文
+    Senimed,
+    /// 学术论文领域
+    ///
+    /// - 中文-->英语
+    /// - 英语-->中文
+    Academic,
+    /// 航空航天领域
+    ///
+    /// - 中文-->英语
+    /// - 英语-->中文
+    Aerospace,
+    /// 人文社科领域
+    ///
+    /// - 中文-->英语
+    Wiki,
+    /// 新闻资讯领域
+    ///
+    /// - 中文-->英语
+    /// - 英语-->中文
+    News,
+    /// 法律法规领域
+    ///
+    /// - 中文-->英语
+    /// - 英语-->中文
+    Law,
+    /// 合同领域
+    ///
+    /// - 中文-->英语
+    /// - 英语-->中文
+    Contract,
+    /// 其他领域，值原样作为`domain`参数发送
+    Other(String),
 }
 
 impl Display for Domain {
@@ -34,6 +90,16 @@ impl Display for Domain {
             Domain::Mechanics => "mechanics",
             Domain::Medicine => "medicine",
             Domain::Novel => "novel",
+            Domain::It => "it",
+            Domain::Machinery => "machinery",
+            Domain::Senimed => "senimed",
+            Domain::Academic => "academic",
+            Domain::Aerospace => "aerospace",
+            Domain::Wiki => "wiki",
+            Domain::News => "news",
+            Domain::Law => "law",
+            Domain::Contract => "contract",
+            Domain::Other(domain) => domain,
         };
         write!(f, "{}", domain_str)
     }
@@ -42,6 +108,7 @@ impl Display for Domain {
 impl FromStr for Domain {
     type Err = String;
 
+    /// 未知的领域解析为[`Domain::Other`]，空字符串返回错误
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "electronics" => Ok(Domain::Electronics),
@@ -49,7 +116,37 @@ impl FromStr for Domain {
             "mechanics" => Ok(Domain::Mechanics),
             "medicine" => Ok(Domain::Medicine),
             "novel" => Ok(Domain::Novel),
-            _ => Err("暂不支持的垂直领域类型".to_string()),
+            "it" => Ok(Domain::It),
+            "machinery" => Ok(Domain::Machinery),
+            "senimed" => Ok(Domain::Senimed),
+            "academic" => Ok(Domain::Academic),
+            "aerospace" => Ok(Domain::Aerospace),
+            "wiki" => Ok(Domain::Wiki),
+            "news" => Ok(Domain::News),
+            "law" => Ok(Domain::Law),
+            "contract" => Ok(Domain::Contract),
+            "" => Err("垂直领域不能为空".to_string()),
+            _ => Ok(Domain::Other(s.to_string())),
         }
     }
 }
+
+impl<'de> Deserialize<'de> for Domain {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Domain {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
@@ -2,10 +2,11 @@
 
 use serde::Deserialize;
 
-use crate::model::TranslateResult;
+use crate::model::{ErrorCode, TranslateResult};
 
 /// 垂直领域返回结构
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct DomainResult {
     /// 源语言
     pub from: Option<String>,
@@ -14,7 +15,30 @@ pub struct DomainResult {
     /// 翻译结果
     pub trans_result: Option<Vec<TranslateResult>>,
     /// 错误码
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// 错误消息
     pub error_msg: Option<String>,
+    /// 实际使用的翻译方式，只有回退翻译时不是[`Route::Domain`]
+    #[serde(default)]
+    pub route: Route,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 翻译方式
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[serde(rename_all = "snake_case")]
+pub enum Route {
+    /// 垂直领域翻译
+    #[default]
+    Domain,
+    /// 回退到通用翻译
+    General,
+    /// 经过中转语言，其中一段使用垂直领域翻译
+    Pivot {
+        /// 中转语言
+        lang: String,
+    },
 }
@@ -0,0 +1,421 @@
+//! 客户端术语表
+//!
+//! 百度的"我的术语"需要在控制台维护，无法按请求指定。这里在本地维护术语表（按语言对区分），
+//! 翻译前把原文中的术语替换为占位符，翻译后再把占位符还原为指定的译文，
+//! 百度改写或丢失的占位符会在结果中列出。
+//!
+//! 术语表可以从CSV（两列：原文术语,译文术语）或者TBX文件加载。
+//!
+//! 需要开启`glossary` feature
+//!
+//! ```toml
+//! baidu_trans = { version = "0.8.0", features = ["glossary"] }
+//! ```
+use std::{io::Read, path::Path};
+
+use crate::{lang::Lang, model::CommonResult};
+
+/// 术语
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Term {
+    /// 源语言
+    pub from: Lang,
+    /// 目标语言
+    pub to: Lang,
+    /// 原文术语
+    pub source: String,
+    /// 指定的译文
+    pub target: String,
+}
+
+/// 术语表
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Glossary {
+    terms: Vec<Term>,
+}
+
+impl Glossary {
+    /// 创建空术语表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加术语，同一语言对下已经存在的原文术语会被覆盖
+    pub fn add(
+        &mut self,
+        from: Lang,
+        to: Lang,
+        source: impl Into<String>,
+        target: impl Into<String>,
+    ) {
+        let source = source.into();
+        let target = target.into();
+        if source.is_empty() {
+            return;
+        }
+
+        match self
+            .terms
+            .iter_mut()
+            .find(|t| t.from == from && t.to == to && t.source == source)
+        {
+            Some(term) => term.target = target,
+            None => self.terms.push(Term {
+                from,
+                to,
+                source,
+                target,
+            }),
+        }
+    }
+
+    /// 合并另一个术语表
+    pub fn extend(&mut self, other: Glossary) {
+        for term in other.terms {
+            self.add(term.from, term.to, term.source, term.target);
+        }
+    }
+
+    /// 所有术语
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+
+    /// 术语数量
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// 从CSV读取术语，每行`原文术语,译文术语`，`#`开头的行为注释，
+    /// 第一行为`source,target`时作为表头跳过
+    /// - reader: CSV数据
+    /// - from: 源语言
+    /// - to: 目标语言
+    pub fn from_csv(reader: impl Read, from: Lang, to: Lang) -> anyhow::Result<Self> {
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .comment(Some(b'#'))
+            .trim(csv::Trim::All)
+            .from_reader(reader);
+
+        let mut glossary = Self::new();
+        for (i, record) in reader.records().enumerate() {
+            let record = record?;
+            let (Some(source), Some(target)) = (record.get(0), record.get(1)) else {
+                continue;
+            };
+            if i == 0
+                && source.eq_ignore_ascii_case("source")
+                && target.eq_ignore_ascii_case("target")
+            {
+                continue;
+            }
+            glossary.add(from, to, source, target);
+        }
+
+        Ok(glossary)
+    }
+
+    /// 从CSV文件读取术语，格式见[`Glossary::from_csv`]
+    pub fn load_csv(path: impl AsRef<Path>, from: Lang, to: Lang) -> anyhow::Result<Self> {
+        Self::from_csv(std::fs::File::open(path)?, from, to)
+    }
+
+    /// 从TBX读取术语
+    ///
+    /// 每个`termEntry`（TBX v3为`conceptEntry`）中不同语言的术语两两组成语言对，
+    /// 无法识别的语言会被忽略
+    pub fn from_tbx(xml: &str) -> anyhow::Result<Self> {
+        let options = roxmltree::ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        };
+        let doc = roxmltree::Document::parse_with_options(xml, options)?;
+
+        let mut glossary = Self::new();
+        for entry in doc
+            .descendants()
+            .filter(|n| matches!(n.tag_name().name(), "termEntry" | "conceptEntry"))
+        {
+            let terms: Vec<(Lang, String)> = entry
+                .children()
+                .filter(|n| matches!(n.tag_name().name(), "langSet" | "langSec"))
+                .filter_map(|set| {
+                    let lang = tbx_lang(set.attribute((XML_NS, "lang"))?)?;
+                    let term = set
+                        .descendants()
+                        .find(|n| n.tag_name().name() == "term")?
+                        .text()?
+                        .trim()
+                        .to_string();
+                    Some((lang, term))
+                })
+                .collect();
+
+            for (from, source) in &terms {
+                for (to, target) in &terms {
+                    if from != to {
+                        glossary.add(*from, *to, source.as_str(), target.as_str());
+                    }
+                }
+            }
+        }
+
+        Ok(glossary)
+    }
+
+    /// 从TBX文件读取术语，格式见[`Glossary::from_tbx`]
+    pub fn load_tbx(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        Self::from_tbx(&std::fs::read_to_string(path)?)
+    }
+
+    /// 把原文中的术语替换为占位符
+    ///
+    /// 优先匹配较长的术语；由字母数字组成的术语只匹配完整单词。
+    /// 源语言为[`Lang::Auto`]时使用所有目标语言匹配的术语。
+    /// - text: 原文
+    /// - from: 源语言
+    /// - to: 目标语言
+    pub fn protect(&self, text: &str, from: Lang, to: Lang) -> Protected {
+        let mut terms: Vec<&Term> = Vec::new();
+        for term in self
+            .terms
+            .iter()
+            .filter(|t| (from == Lang::Auto || t.from == from) && t.to == to)
+        {
+            if !terms.iter().any(|t| t.source == term.source) {
+                terms.push(term);
+            }
+        }
+        terms.sort_by_key(|t| std::cmp::Reverse(t.source.len()));
+
+        let mut placeholders: Vec<Placeholder> = Vec::new();
+        let mut out = String::with_capacity(text.len());
+        let mut i = 0;
+        while i < text.len() {
+            let rest = &text[i..];
+            let matched = terms
+                .iter()
+                .find(|t| rest.starts_with(t.source.as_str()) && is_boundary(text, i, &t.source));
+
+            match matched {
+                Some(term) => {
+                    let token = match placeholders.iter().find(|p| p.source == term.source) {
+                        Some(p) => p.token.clone(),
+                        None => {
+                            let token = format!("{{T{}}}", placeholders.len());
+                            placeholders.push(Placeholder {
+                                token: token.clone(),
+                                source: term.source.clone(),
+                                target: term.target.clone(),
+                            });
+                            token
+                        }
+                    };
+                    out.push_str(&token);
+                    i += term.source.len();
+                }
+                None => {
+                    let c = rest.chars().next().unwrap();
+                    out.push(c);
+                    i += c.len_utf8();
+                }
+            }
+        }
+
+        Protected {
+            text: out,
+            placeholders,
+        }
+    }
+}
+
+/// 占位符
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder {
+    /// 发送给百度的占位符，如`{T0}`
+    pub token: String,
+    /// 原文术语
+    pub source: String,
+    /// 指定的译文
+    pub target: String,
+}
+
+/// 替换术语后的原文
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Protected {
+    /// 替换后的文本
+    pub text: String,
+    /// 使用的占位符
+    pub placeholders: Vec<Placeholder>,
+}
+
+impl Protected {
+    /// 把译文中的占位符还原为指定的译文
+    ///
+    /// 百度在占位符中插入空格或者改为全角括号时仍然可以还原，
+    /// 找不到的占位符会记录在[`Restored::mangled`]中
+    pub fn restore(&self, translated: &str) -> Restored {
+        self.restore_expected(translated, &self.text)
+    }
+
+    /// 把文本中的占位符还原为原文术语，用于还原百度返回的`src`
+    pub fn restore_source(&self, text: &str) -> String {
+        self.placeholders.iter().fold(text.to_string(), |text, p| {
+            text.replace(&p.token, &p.source)
+        })
+    }
+
+    /// 还原通用翻译结果中每一段的原文和译文
+    pub fn restore_result(&self, mut result: CommonResult) -> GlossaryResult {
+        let mut mangled = Vec::new();
+        for item in result.trans_result.iter_mut().flatten() {
+            let restored = self.restore_expected(&item.dst, &item.src);
+            item.src = self.restore_source(&item.src);
+            item.dst = restored.text;
+            for p in restored.mangled {
+                if !mangled.contains(&p) {
+                    mangled.push(p);
+                }
+            }
+        }
+
+        GlossaryResult { result, mangled }
+    }
+
+    /// 还原译文，只有在`source`中出现的占位符缺失时才记为被改写
+    fn restore_expected(&self, translated: &str, source: &str) -> Restored {
+        let mut text = translated.to_string();
+        let mut mangled = Vec::new();
+        for p in &self.placeholders {
+            let mut found = false;
+            if text.contains(&p.token) {
+                text = text.replace(&p.token, &p.target);
+                found = true;
+            }
+            let mut from = 0;
+            while let Some((start, end)) = find_loose(&text[from..], &p.token) {
+                text.replace_range(from + start..from + end, &p.target);
+                from += start + p.target.len();
+                found = true;
+            }
+            if !found && source.contains(&p.token) {
+                mangled.push(p.clone());
+            }
+        }
+
+        Restored { text, mangled }
+    }
+}
+
+/// 还原后的译文
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Restored {
+    /// 还原后的文本
+    pub text: String,
+    /// 被百度改写或丢失的占位符，对应的术语没有被替换
+    pub mangled: Vec<Placeholder>,
+}
+
+/// 使用术语表的翻译结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryResult {
+    /// 还原术语后的翻译结果
+    pub result: CommonResult,
+    /// 被百度改写或丢失的占位符
+    pub mangled: Vec<Placeholder>,
+}
+
+const XML_NS: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// 把TBX中的语言代码转换为百度的语种
+fn tbx_lang(code: &str) -> Option<Lang> {
+    let code = code.trim().to_ascii_lowercase().replace('_', "-");
+    let lang = match code.as_str() {
+        "zh-tw" | "zh-hk" | "zh-mo" | "zh-hant" | "cht" => Lang::Cht,
+        "yue" => Lang::Yue,
+        "wyw" => Lang::Wyw,
+        _ => match code.split('-').next().unwrap_or_default() {
+            "zh" => Lang::Zh,
+            "en" => Lang::En,
+            "ja" | "jp" => Lang::Jp,
+            "ko" | "kor" => Lang::Kor,
+            "fr" | "fra" => Lang::Fra,
+            "es" | "spa" => Lang::Spa,
+            "th" => Lang::Th,
+            "ar" | "ara" => Lang::Ara,
+            "ru" => Lang::Ru,
+            "pt" => Lang::Pt,
+            "de" => Lang::De,
+            "it" => Lang::It,
+            "el" => Lang::El,
+            "nl" => Lang::Nl,
+            "pl" => Lang::Pl,
+            "bg" | "bul" => Lang::Bul,
+            "et" | "est" => Lang::Est,
+            "da" | "dan" => Lang::Dan,
+            "fi" | "fin" => Lang::Fin,
+            "cs" => Lang::Cs,
+            "ro" | "rom" => Lang::Rom,
+            "sl" | "slo" => Lang::Slo,
+            "sv" | "swe" => Lang::Swe,
+            "hu" => Lang::Hu,
+            "vi" | "vie" => Lang::Vie,
+            _ => return None,
+        },
+    };
+
+    Some(lang)
+}
+
+/// 由字母数字组成的术语两侧不能紧邻字母数字，避免匹配到单词的一部分
+fn is_boundary(text: &str, start: usize, term: &str) -> bool {
+    let is_word = |c: char| c.is_ascii_alphanumeric();
+    let before = text[..start].chars().next_back();
+    let after = text[start + term.len()..].chars().next();
+
+    let split_start = term.starts_with(is_word) && before.is_some_and(is_word);
+    let split_end = term.ends_with(is_word) && after.is_some_and(is_word);
+
+    !split_start && !split_end
+}
+
+/// 宽松查找占位符：忽略空白，兼容全角括号和小写字母
+fn find_loose(text: &str, token: &str) -> Option<(usize, usize)> {
+    let normalize = |c: char| match c {
+        '｛' => '{',
+        '｝' => '}',
+        c => c.to_ascii_uppercase(),
+    };
+    let token: Vec<char> = token.chars().collect();
+
+    for (start, _) in text.char_indices() {
+        let mut chars = text[start..].char_indices().peekable();
+        let mut matched = 0;
+        let mut end = start;
+        while matched < token.len() {
+            let Some((offset, c)) = chars.next() else {
+                break;
+            };
+            if matched > 0 && c.is_whitespace() {
+                continue;
+            }
+            if normalize(c) != token[matched] {
+                break;
+            }
+            matched += 1;
+            end = start + offset + c.len_utf8();
+        }
+        if matched == token.len() {
+            return Some((start, end));
+        }
+    }
+
+    None
+}
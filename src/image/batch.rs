@@ -0,0 +1,123 @@
+//! 批量翻译目录中的图片
+//!
+//! 每张图片的翻译结果保存在图片旁边的`<文件名>.json`中，开启[`BatchOptions::save_paste`]时，
+//! 整图贴合图片保存为`<文件名>.paste.jpg`。已经存在成功结果的图片在再次运行时会被跳过。
+//!
+//! 需要开启`batch` feature，只支持异步客户端
+//!
+//! ```toml
+//! baidu_trans = { version = "0.8.0", features = ["batch"] }
+//! ```
+use std::path::{Path, PathBuf};
+
+use super::model::ImageResult;
+
+/// 批量翻译参数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchOptions {
+    /// 包含的文件，glob模式，匹配相对于目录的路径，默认为常见图片格式
+    pub include: Vec<String>,
+    /// 排除的文件，glob模式，匹配相对于目录的路径
+    pub exclude: Vec<String>,
+    /// 是否遍历子目录，默认是
+    pub recursive: bool,
+    /// 同时进行的请求数，默认4
+    pub concurrency: usize,
+    /// 是否保存整图贴合图片，需要配合[`PasteMode::FullImage`](super::PasteMode::FullImage)使用
+    pub save_paste: bool,
+    /// 是否重新翻译已经有成功结果的图片，默认跳过
+    pub overwrite: bool,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            include: ["*.png", "*.jpg", "*.jpeg", "*.webp", "*.bmp"]
+                .map(String::from)
+                .to_vec(),
+            exclude: Vec::new(),
+            recursive: true,
+            concurrency: 4,
+            save_paste: false,
+            overwrite: false,
+        }
+    }
+}
+
+/// 单张图片的处理状态
+#[derive(Debug)]
+pub enum BatchStatus {
+    /// 已有成功结果，跳过
+    Skipped,
+    /// 翻译成功
+    Done,
+    /// 翻译失败
+    Failed(anyhow::Error),
+}
+
+/// 进度信息
+#[derive(Debug)]
+pub struct BatchProgress<'a> {
+    /// 图片路径
+    pub path: &'a Path,
+    /// 处理状态
+    pub status: &'a BatchStatus,
+    /// 已处理的图片数
+    pub completed: usize,
+    /// 图片总数
+    pub total: usize,
+}
+
+/// 批量翻译结果
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    /// 翻译成功的图片
+    pub translated: Vec<PathBuf>,
+    /// 跳过的图片
+    pub skipped: Vec<PathBuf>,
+    /// 翻译失败的图片
+    pub failed: Vec<(PathBuf, anyhow::Error)>,
+}
+
+impl BatchReport {
+    pub(crate) fn record(&mut self, path: PathBuf, status: BatchStatus) {
+        match status {
+            BatchStatus::Skipped => self.skipped.push(path),
+            BatchStatus::Done => self.translated.push(path),
+            BatchStatus::Failed(err) => self.failed.push((path, err)),
+        }
+    }
+}
+
+/// 翻译结果的保存路径
+pub fn result_path(path: &Path) -> PathBuf {
+    with_suffix(path, ".json")
+}
+
+/// 整图贴合图片的保存路径
+pub fn paste_path(path: &Path) -> PathBuf {
+    with_suffix(path, ".paste.jpg")
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// 是否已经有成功的翻译结果
+pub(crate) async fn is_completed(path: &Path) -> bool {
+    let Ok(data) = tokio::fs::read(result_path(path)).await else {
+        return false;
+    };
+
+    serde_json::from_slice::<ImageResult>(&data).is_ok_and(|result| result.error_code.is_success())
+}
+
+/// 遍历目录，排除批量翻译自身输出的贴合图片
+pub(crate) fn collect(dir: &Path, options: &BatchOptions) -> anyhow::Result<Vec<PathBuf>> {
+    let mut exclude = options.exclude.clone();
+    exclude.push("*.paste.jpg".to_string());
+
+    crate::util::walk_files(dir, &options.include, &exclude, options.recursive)
+}
@@ -0,0 +1,239 @@
+//! 将图片翻译结果导出为常见的OCR格式
+//!
+//! - [hOCR](http://kba.github.io/hocr-spec/1.2/)
+//! - [ALTO XML](https://www.loc.gov/standards/alto/) v4
+//! - COCO风格的标注JSON
+//!
+//! 原文作为识别文字导出，译文以各格式的扩展方式附带：hOCR使用`data-dst`属性，
+//! ALTO使用`ALTERNATIVE`元素，COCO放在`attributes`中。
+use std::fmt::Write;
+
+use serde_json::json;
+
+use super::{
+    geometry::Rect,
+    model::{Content, ImageResult},
+};
+
+/// 图片信息
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Page<'a> {
+    /// 图片文件名
+    pub name: &'a str,
+    /// 图片宽度
+    pub width: u32,
+    /// 图片高度
+    pub height: u32,
+}
+
+impl<'a> Page<'a> {
+    /// 创建图片信息
+    pub fn new(name: &'a str, width: u32, height: u32) -> Self {
+        Self {
+            name,
+            width,
+            height,
+        }
+    }
+}
+
+/// 导出为hOCR
+pub fn to_hocr(result: &ImageResult, page: &Page) -> String {
+    let (from, to) = langs(result);
+    let mut out = String::new();
+
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<!DOCTYPE html PUBLIC \"-//W3C//DTD XHTML 1.0 Transitional//EN\" \"http://www.w3.org/TR/xhtml1/DTD/xhtml1-transitional.dtd\">\n");
+    let _ = writeln!(
+        out,
+        "<html xmlns=\"http://www.w3.org/1999/xhtml\" xml:lang=\"{0}\" lang=\"{0}\">",
+        escape(from)
+    );
+    out.push_str("<head>\n");
+    let _ = writeln!(out, "<title>{}</title>", escape(page.name));
+    out.push_str("<meta http-equiv=\"Content-Type\" content=\"text/html; charset=utf-8\" />\n");
+    out.push_str("<meta name=\"ocr-system\" content=\"baidu_trans\" />\n");
+    out.push_str(
+        "<meta name=\"ocr-capabilities\" content=\"ocr_page ocr_carea ocr_par ocr_line\" />\n",
+    );
+    out.push_str("</head>\n<body>\n");
+    let _ = writeln!(
+        out,
+        "<div class=\"ocr_page\" id=\"page_1\" title=\"image &quot;{}&quot;; bbox 0 0 {} {}\" data-dst-lang=\"{}\">",
+        escape(page.name),
+        page.width,
+        page.height,
+        escape(to)
+    );
+
+    for (i, content) in contents(result).enumerate() {
+        let id = i + 1;
+        let bbox = hocr_bbox(&content.region());
+        let _ = writeln!(
+            out,
+            "<div class=\"ocr_carea\" id=\"block_1_{id}\" title=\"{bbox}\">\n<p class=\"ocr_par\" id=\"par_1_{id}\" lang=\"{}\" title=\"{bbox}\">",
+            escape(from)
+        );
+        let _ = writeln!(
+            out,
+            "<span class=\"ocr_line\" id=\"line_1_{id}\" title=\"{bbox}{}; textangle {:.2}\" data-dst=\"{}\">{}</span>",
+            hocr_poly(content),
+            -content.angle(),
+            escape(&content.dst),
+            escape(&content.src)
+        );
+        out.push_str("</p>\n</div>\n");
+    }
+
+    out.push_str("</div>\n</body>\n</html>\n");
+    out
+}
+
+/// 导出为ALTO XML(v4)
+pub fn to_alto(result: &ImageResult, page: &Page) -> String {
+    let (from, to) = langs(result);
+    let mut out = String::new();
+
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<alto xmlns=\"http://www.loc.gov/standards/alto/ns-v4#\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xsi:schemaLocation=\"http://www.loc.gov/standards/alto/ns-v4# http://www.loc.gov/standards/alto/v4/alto-4-2.xsd\">\n");
+    out.push_str("<Description>\n<MeasurementUnit>pixel</MeasurementUnit>\n");
+    let _ = writeln!(
+        out,
+        "<sourceImageInformation>\n<fileName>{}</fileName>\n</sourceImageInformation>",
+        escape(page.name)
+    );
+    out.push_str("</Description>\n<Layout>\n");
+    let _ = writeln!(
+        out,
+        "<Page ID=\"page_1\" PHYSICAL_IMG_NR=\"1\" WIDTH=\"{0}\" HEIGHT=\"{1}\">\n<PrintSpace HPOS=\"0\" VPOS=\"0\" WIDTH=\"{0}\" HEIGHT=\"{1}\">",
+        page.width, page.height
+    );
+
+    for (i, content) in contents(result).enumerate() {
+        let id = i + 1;
+        let pos = alto_pos(&content.region());
+        let _ = writeln!(
+            out,
+            "<TextBlock ID=\"block_{id}\" {pos} LANG=\"{}\">",
+            escape(from)
+        );
+        if !content.points.is_empty() {
+            let points = content
+                .points
+                .iter()
+                .map(|p| format!("{},{}", p.x, p.y))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let _ = writeln!(out, "<Shape><Polygon POINTS=\"{}\"/></Shape>", points);
+        }
+        let _ = writeln!(
+            out,
+            "<TextLine ID=\"line_{id}\" {pos}>\n<String ID=\"string_{id}\" {pos} CONTENT=\"{}\">\n<ALTERNATIVE PURPOSE=\"translation:{}\">{}</ALTERNATIVE>\n</String>\n</TextLine>\n</TextBlock>",
+            escape(&content.src),
+            escape(to),
+            escape(&content.dst)
+        );
+    }
+
+    out.push_str("</PrintSpace>\n</Page>\n</Layout>\n</alto>\n");
+    out
+}
+
+/// 导出为COCO风格的标注JSON，每个分段是一个`text`类别的标注
+pub fn to_coco(result: &ImageResult, page: &Page) -> serde_json::Value {
+    let (from, to) = langs(result);
+    let annotations = contents(result)
+        .enumerate()
+        .map(|(i, content)| {
+            let region = content.region();
+            let segmentation: Vec<i64> = content.points.iter().flat_map(|p| [p.x, p.y]).collect();
+            json!({
+                "id": i + 1,
+                "image_id": 1,
+                "category_id": 1,
+                "bbox": [region.left, region.top, region.width, region.height],
+                "area": region.width * region.height,
+                "segmentation": if segmentation.is_empty() { vec![] } else { vec![segmentation] },
+                "iscrowd": 0,
+                "attributes": {
+                    "src": content.src,
+                    "dst": content.dst,
+                    "from": from,
+                    "to": to,
+                    "line_count": content.line_count,
+                    "angle": content.angle(),
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "info": { "description": "baidu_trans image translation" },
+        "images": [{
+            "id": 1,
+            "file_name": page.name,
+            "width": page.width,
+            "height": page.height,
+        }],
+        "categories": [{ "id": 1, "name": "text" }],
+        "annotations": annotations,
+    })
+}
+
+fn contents(result: &ImageResult) -> impl Iterator<Item = &Content> {
+    result.data.iter().flat_map(|data| data.content.iter())
+}
+
+fn langs(result: &ImageResult) -> (&str, &str) {
+    result
+        .data
+        .as_ref()
+        .map_or(("", ""), |data| (data.from.as_str(), data.to.as_str()))
+}
+
+fn hocr_bbox(rect: &Rect) -> String {
+    format!(
+        "bbox {} {} {} {}",
+        rect.left,
+        rect.top,
+        rect.right(),
+        rect.bottom()
+    )
+}
+
+fn hocr_poly(content: &Content) -> String {
+    if content.points.is_empty() {
+        return String::new();
+    }
+
+    let points = content
+        .points
+        .iter()
+        .map(|p| format!("{} {}", p.x, p.y))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!("; poly {}", points)
+}
+
+fn alto_pos(rect: &Rect) -> String {
+    format!(
+        "HPOS=\"{}\" VPOS=\"{}\" WIDTH=\"{}\" HEIGHT=\"{}\"",
+        rect.left, rect.top, rect.width, rect.height
+    )
+}
+
+/// XML转义
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
@@ -0,0 +1,188 @@
+//! 图片翻译结果的坐标相关结构
+use std::{fmt::Display, str::FromStr};
+
+use super::model::{Content, Data, Point};
+
+/// 矩形区域，坐标原点为图片左上角
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Rect {
+    /// 左边界
+    pub left: i64,
+    /// 上边界
+    pub top: i64,
+    /// 宽度
+    pub width: i64,
+    /// 高度
+    pub height: i64,
+}
+
+impl Rect {
+    /// 右边界
+    pub fn right(&self) -> i64 {
+        self.left + self.width
+    }
+
+    /// 下边界
+    pub fn bottom(&self) -> i64 {
+        self.top + self.height
+    }
+
+    /// 中心点
+    pub fn center(&self) -> (f64, f64) {
+        (
+            self.left as f64 + self.width as f64 / 2.0,
+            self.top as f64 + self.height as f64 / 2.0,
+        )
+    }
+
+    /// 按比例缩放
+    pub fn scale(&self, factor: f64) -> Rect {
+        let scale = |v: i64| (v as f64 * factor).round() as i64;
+        Rect {
+            left: scale(self.left),
+            top: scale(self.top),
+            width: scale(self.width),
+            height: scale(self.height),
+        }
+    }
+
+    /// 包含所有坐标点的最小矩形，没有坐标点时返回`None`
+    pub fn bounding(points: &[Point]) -> Option<Rect> {
+        let left = points.iter().map(|p| p.x).min()?;
+        let top = points.iter().map(|p| p.y).min()?;
+        let right = points.iter().map(|p| p.x).max()?;
+        let bottom = points.iter().map(|p| p.y).max()?;
+
+        Some(Rect {
+            left,
+            top,
+            width: right - left,
+            height: bottom - top,
+        })
+    }
+}
+
+impl Display for Rect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} {}",
+            self.left, self.top, self.width, self.height
+        )
+    }
+}
+
+impl FromStr for Rect {
+    type Err = anyhow::Error;
+
+    /// 解析`"left top width height"`格式的字符串
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split_whitespace()
+            .map(|v| v.parse::<f64>().map(|v| v.round() as i64))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match values[..] {
+            [left, top, width, height] => Ok(Rect {
+                left,
+                top,
+                width,
+                height,
+            }),
+            _ => Err(anyhow::anyhow!("无效的矩形坐标: {}", s)),
+        }
+    }
+}
+
+impl Content {
+    /// 解析识别出的文字位置
+    pub fn rect(&self) -> anyhow::Result<Rect> {
+        self.rect.parse()
+    }
+
+    /// 译文坐标点的外接矩形，没有坐标点时返回`None`
+    pub fn bounding_box(&self) -> Option<Rect> {
+        Rect::bounding(&self.points)
+    }
+
+    /// 译文区域的旋转角度(角度制)，按第一条边(左上→右上)计算，顺时针为正
+    pub fn angle(&self) -> f64 {
+        match &self.points[..] {
+            [a, b, ..] => ((b.y - a.y) as f64).atan2((b.x - a.x) as f64).to_degrees(),
+            _ => 0.0,
+        }
+    }
+
+    /// 译文区域的形心，没有坐标点时返回`None`
+    pub fn centroid(&self) -> Option<(f64, f64)> {
+        let points = &self.points;
+        if points.is_empty() {
+            return None;
+        }
+
+        // 多边形面积为0时(如点重合)退化为坐标平均值
+        let mut area = 0.0;
+        let (mut cx, mut cy) = (0.0, 0.0);
+        for (i, a) in points.iter().enumerate() {
+            let b = &points[(i + 1) % points.len()];
+            let cross = (a.x * b.y - b.x * a.y) as f64;
+            area += cross;
+            cx += (a.x + b.x) as f64 * cross;
+            cy += (a.y + b.y) as f64 * cross;
+        }
+
+        if area.abs() < f64::EPSILON {
+            let n = points.len() as f64;
+            let x = points.iter().map(|p| p.x as f64).sum::<f64>() / n;
+            let y = points.iter().map(|p| p.y as f64).sum::<f64>() / n;
+            return Some((x, y));
+        }
+
+        Some((cx / (3.0 * area), cy / (3.0 * area)))
+    }
+
+    /// 用于排序的区域，优先使用`rect`，解析失败时使用坐标点的外接矩形
+    pub(crate) fn region(&self) -> Rect {
+        self.rect()
+            .ok()
+            .or_else(|| self.bounding_box())
+            .unwrap_or_default()
+    }
+}
+
+impl Data {
+    /// 将分段内容按阅读顺序(从上到下、从左到右)排序
+    ///
+    /// 垂直方向重叠超过一半高度的分段视为同一行
+    pub fn sort_reading_order(&mut self) {
+        let mut content = std::mem::take(&mut self.content);
+        content.sort_by_key(|c| c.region().top);
+
+        let mut lines: Vec<Vec<Content>> = Vec::new();
+        for item in content {
+            let region = item.region();
+            let same_line = lines
+                .last()
+                .and_then(|line| line.first())
+                .is_some_and(|first| {
+                    let first = first.region();
+                    let overlap = first.bottom().min(region.bottom()) - first.top.max(region.top);
+                    overlap * 2 > first.height.min(region.height)
+                });
+
+            match lines.last_mut() {
+                Some(line) if same_line => line.push(item),
+                _ => lines.push(vec![item]),
+            }
+        }
+
+        self.content = lines
+            .into_iter()
+            .flat_map(|mut line| {
+                line.sort_by_key(|c| c.region().left);
+                line
+            })
+            .collect();
+    }
+}
@@ -1,2 +1,122 @@
 //! 图片翻译相关模块
+use std::fmt::Display;
+
+#[cfg(feature = "batch")]
+pub mod batch;
+pub mod export;
+pub mod geometry;
 pub mod model;
+pub mod ocr;
+#[cfg(feature = "preprocess")]
+pub mod preprocess;
+#[cfg(feature = "render")]
+pub mod render;
+
+/// 图片贴合类型
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PasteMode {
+    /// 关闭文字贴合
+    #[default]
+    Off,
+    /// 返回整图贴合，结果在[`model::Data::paste_img`]
+    FullImage,
+    /// 返回块区贴合，结果在[`model::Content::paste_img`]
+    PerBlock,
+}
+
+impl Display for PasteMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let paste = match self {
+            PasteMode::Off => "0",
+            PasteMode::FullImage => "1",
+            PasteMode::PerBlock => "2",
+        };
+        write!(f, "{}", paste)
+    }
+}
+
+/// 图片翻译请求参数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageRequest {
+    /// 图片贴合类型，默认关闭
+    pub paste: PasteMode,
+    /// 设备id，默认`APICUID`
+    pub cuid: String,
+    /// 设备mac，默认`mac`
+    pub mac: String,
+    /// 接口版本，默认`3`
+    pub version: String,
+    /// 上传前预处理图片，默认不处理
+    #[cfg(feature = "preprocess")]
+    pub preprocess: Option<preprocess::PreprocessOptions>,
+}
+
+impl Default for ImageRequest {
+    fn default() -> Self {
+        Self {
+            paste: PasteMode::default(),
+            cuid: "APICUID".to_string(),
+            mac: "mac".to_string(),
+            version: "3".to_string(),
+            #[cfg(feature = "preprocess")]
+            preprocess: None,
+        }
+    }
+}
+
+impl ImageRequest {
+    /// 设置图片贴合类型
+    pub fn paste(mut self, paste: PasteMode) -> Self {
+        self.paste = paste;
+        self
+    }
+
+    /// 设置设备id
+    pub fn cuid(mut self, cuid: impl Into<String>) -> Self {
+        self.cuid = cuid.into();
+        self
+    }
+
+    /// 设置设备mac
+    pub fn mac(mut self, mac: impl Into<String>) -> Self {
+        self.mac = mac.into();
+        self
+    }
+
+    /// 设置接口版本
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// 上传前预处理图片，识别结果的坐标会还原到原图坐标系
+    #[cfg(feature = "preprocess")]
+    pub fn preprocess(mut self, options: preprocess::PreprocessOptions) -> Self {
+        self.preprocess = Some(options);
+        self
+    }
+}
+
+/// 取路径中的文件名作为上传的文件名
+pub(crate) fn file_name(path: &std::path::Path) -> anyhow::Result<String> {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .ok_or_else(|| anyhow::anyhow!("无效的图片路径: {}", path.display()))
+}
+
+/// 按请求参数预处理图片，返回上传的文件名、图片数据以及预处理信息
+#[cfg(feature = "preprocess")]
+pub(crate) fn prepare(
+    request: &ImageRequest,
+    name: &str,
+    data: Vec<u8>,
+) -> anyhow::Result<(String, Vec<u8>, Option<preprocess::Prepared>)> {
+    match &request.preprocess {
+        Some(options) => {
+            let mut prepared = preprocess::prepare(&data, options)?;
+            let data = std::mem::take(&mut prepared.data);
+            Ok((prepared.file_name(name), data, Some(prepared)))
+        }
+        None => Ok((name.to_string(), data, None)),
+    }
+}
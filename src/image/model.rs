@@ -1,19 +1,26 @@
 //! 图片翻译返回的相关结构
 use serde::Deserialize;
 
+use crate::model::ErrorCode;
+
 /// 图片翻译返回的结构
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ImageResult {
     /// 错误码
-    pub error_code: String,
+    pub error_code: ErrorCode,
     /// 错误消息
     pub error_msg: String,
     /// 返回数据集合
     pub data: Option<Data>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 返回结果
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Data {
     /// 源语种方向
@@ -27,11 +34,16 @@ pub struct Data {
     /// 未分段翻译译文
     pub sum_dst: String,
     /// 图片贴合 (整屏贴合)，paste=1有效，base64格式
+    #[serde(default)]
     pub paste_img: String,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 翻译内容
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Content {
     /// 分段翻译的原文
@@ -54,14 +66,81 @@ pub struct Content {
     /// ```
     pub points: Vec<Point>,
     /// 图片贴合 (分块贴合)，分段贴合图片，paste=2有效，base64格式
+    #[serde(default)]
     pub paste_img: String,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 矩阵坐标
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Point {
     /// x坐标
     pub x: i64,
     /// y坐标
     pub y: i64,
 }
+
+impl ImageResult {
+    /// 按比例缩放识别结果中的坐标(`rect`和`points`)
+    pub fn rescale(&mut self, factor: f64) {
+        if let Some(data) = &mut self.data {
+            for content in data.content.iter_mut() {
+                content.rescale(factor);
+            }
+        }
+    }
+}
+
+impl Data {
+    /// 解码整图贴合图片，未返回贴合图片时返回`Ok(None)`
+    ///
+    /// 需要使用[`PasteMode::FullImage`](super::PasteMode::FullImage)
+    pub fn paste_image(&self) -> anyhow::Result<Option<Vec<u8>>> {
+        decode_paste_img(&self.paste_img)
+    }
+}
+
+impl Content {
+    /// 按比例缩放`rect`和`points`坐标
+    pub fn rescale(&mut self, factor: f64) {
+        let scale = |v: i64| (v as f64 * factor).round() as i64;
+
+        if let Ok(rect) = self.rect() {
+            self.rect = rect.scale(factor).to_string();
+        }
+
+        for point in self.points.iter_mut() {
+            point.x = scale(point.x);
+            point.y = scale(point.y);
+        }
+    }
+
+    /// 解码分块贴合图片，未返回贴合图片时返回`Ok(None)`
+    ///
+    /// 需要使用[`PasteMode::PerBlock`](super::PasteMode::PerBlock)
+    pub fn paste_image(&self) -> anyhow::Result<Option<Vec<u8>>> {
+        decode_paste_img(&self.paste_img)
+    }
+}
+
+/// 解码base64格式的贴合图片，兼容`data:image/jpeg;base64,`前缀
+fn decode_paste_img(paste_img: &str) -> anyhow::Result<Option<Vec<u8>>> {
+    use base64::Engine;
+
+    let paste_img = paste_img.trim();
+    if paste_img.is_empty() {
+        return Ok(None);
+    }
+
+    let data = match paste_img.split_once(";base64,") {
+        Some((_, data)) => data,
+        None => paste_img,
+    };
+
+    Ok(Some(
+        base64::engine::general_purpose::STANDARD.decode(data)?,
+    ))
+}
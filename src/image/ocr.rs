@@ -0,0 +1,161 @@
+//! 基于图片翻译接口的文字识别
+//!
+//! 只保留图片翻译结果中识别出的原文及其位置
+use crate::{lang::Lang, model::ErrorCode};
+
+use super::{
+    geometry::Rect,
+    model::{ImageResult, Point},
+};
+
+/// 文字识别结果
+#[derive(Debug, Clone, PartialEq, Default, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OcrResult {
+    /// 错误码
+    pub error_code: ErrorCode,
+    /// 错误消息
+    pub error_msg: String,
+    /// 识别出的语种
+    pub lang: String,
+    /// 未分段的识别文字
+    pub text: String,
+    /// 分段识别结果
+    pub segments: Vec<OcrSegment>,
+}
+
+/// 分段识别结果
+#[derive(Debug, Clone, PartialEq, Default, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OcrSegment {
+    /// 识别出的文字
+    pub text: String,
+    /// 文字位置
+    pub rect: Rect,
+    /// 文字区域坐标
+    pub points: Vec<Point>,
+    /// 该分段由原图中的多少行合并而成
+    pub line_count: i64,
+}
+
+/// 合并后的段落
+#[derive(Debug, Clone, PartialEq, Default, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct OcrParagraph {
+    /// 段落文字
+    pub text: String,
+    /// 段落位置
+    pub rect: Rect,
+    /// 段落包含的行数
+    pub line_count: i64,
+}
+
+impl From<ImageResult> for OcrResult {
+    fn from(result: ImageResult) -> Self {
+        let data = result.data.unwrap_or_default();
+        let segments = data
+            .content
+            .into_iter()
+            .map(|content| OcrSegment {
+                rect: content.region(),
+                text: content.src,
+                points: content.points,
+                line_count: content.line_count.max(1),
+            })
+            .collect();
+
+        Self {
+            error_code: result.error_code,
+            error_msg: result.error_msg,
+            lang: data.from,
+            text: data.sum_src,
+            segments,
+        }
+    }
+}
+
+impl OcrResult {
+    /// 将相邻的单行分段合并为段落
+    ///
+    /// `line_count`大于1的分段已经由接口合并过，单独作为一个段落；
+    /// 单行分段与上一行的垂直间距小于行高、且左侧对齐或水平方向有重叠时合并到同一段落。
+    pub fn paragraphs(&self) -> Vec<OcrParagraph> {
+        let mut paragraphs: Vec<OcrParagraph> = Vec::new();
+        let mut open = false;
+
+        for segment in self.segments.iter() {
+            let merged = segment.line_count == 1
+                && open
+                && paragraphs
+                    .last()
+                    .is_some_and(|last| is_next_line(last, segment));
+
+            match paragraphs.last_mut() {
+                Some(last) if merged => {
+                    last.text = join_lines(&last.text, &segment.text);
+                    last.rect = union(&last.rect, &segment.rect);
+                    last.line_count += 1;
+                }
+                _ => paragraphs.push(OcrParagraph {
+                    text: segment.text.clone(),
+                    rect: segment.rect,
+                    line_count: segment.line_count,
+                }),
+            }
+            open = segment.line_count == 1;
+        }
+
+        paragraphs
+    }
+}
+
+/// 判断分段是否是段落的下一行
+fn is_next_line(paragraph: &OcrParagraph, segment: &OcrSegment) -> bool {
+    let line_height = paragraph.rect.height / paragraph.line_count.max(1);
+    let gap = segment.rect.top - paragraph.rect.bottom();
+    if gap < -line_height / 2 || gap > line_height {
+        return false;
+    }
+
+    let aligned = (segment.rect.left - paragraph.rect.left).abs() <= line_height;
+    let overlap =
+        segment.rect.left < paragraph.rect.right() && paragraph.rect.left < segment.rect.right();
+    aligned || overlap
+}
+
+/// 拼接两行文字，上一行以英文字母、数字或标点结尾且下一行以字母数字开头时用空格分隔
+fn join_lines(a: &str, b: &str) -> String {
+    let a = a.trim_end();
+    let b = b.trim_start();
+    let spaced = a
+        .chars()
+        .last()
+        .is_some_and(|c| c.is_ascii_alphanumeric() || c.is_ascii_punctuation())
+        && b.chars().next().is_some_and(|c| c.is_ascii_alphanumeric());
+
+    if spaced {
+        format!("{} {}", a, b)
+    } else {
+        format!("{}{}", a, b)
+    }
+}
+
+fn union(a: &Rect, b: &Rect) -> Rect {
+    let left = a.left.min(b.left);
+    let top = a.top.min(b.top);
+    Rect {
+        left,
+        top,
+        width: a.right().max(b.right()) - left,
+        height: a.bottom().max(b.bottom()) - top,
+    }
+}
+
+/// 图片翻译接口要求指定目标语言，识别时目标语言为自动则选择一个与源语言不同的语种
+pub(crate) fn target_lang(from: Lang, to: Lang) -> Lang {
+    match (from, to) {
+        (Lang::Zh, Lang::Auto) => Lang::En,
+        (_, Lang::Auto) => Lang::Zh,
+        (_, to) => to,
+    }
+}
@@ -0,0 +1,194 @@
+//! 图片预处理，使上传的图片满足百度图片翻译接口的大小和尺寸限制
+//!
+//! 需要开启`preprocess` feature
+//!
+//! ```toml
+//! baidu_trans = { version = "0.8.0", features = ["preprocess"] }
+//! ```
+use std::io::Cursor;
+
+use ::image::{imageops::FilterType, DynamicImage, GenericImageView, ImageFormat};
+
+use super::model::ImageResult;
+
+/// 预处理参数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PreprocessOptions {
+    /// 图片最大字节数，默认4M
+    pub max_bytes: usize,
+    /// 最长边最大像素，默认4096
+    pub max_side: u32,
+    /// 最短边最小像素，默认30
+    pub min_side: u32,
+    /// 重新压缩时JPEG的初始质量，范围1~100，默认90
+    pub jpeg_quality: u8,
+}
+
+impl Default for PreprocessOptions {
+    fn default() -> Self {
+        Self {
+            max_bytes: 4 * 1024 * 1024,
+            max_side: 4096,
+            min_side: 30,
+            jpeg_quality: 90,
+        }
+    }
+}
+
+/// 预处理后的图片
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prepared {
+    /// 上传的图片数据
+    pub data: Vec<u8>,
+    /// 上传的图片格式
+    pub format: ImageFormat,
+    /// 缩放比例，上传图片尺寸 / 原图尺寸
+    pub scale: f64,
+}
+
+impl Prepared {
+    /// 上传时使用的文件扩展名
+    pub fn extension(&self) -> &'static str {
+        self.format
+            .extensions_str()
+            .first()
+            .copied()
+            .unwrap_or("jpg")
+    }
+
+    /// 按原文件名生成上传文件名，格式发生变化时替换扩展名
+    pub fn file_name(&self, name: &str) -> String {
+        let matched = name
+            .rsplit_once('.')
+            .and_then(|(_, ext)| ImageFormat::from_extension(ext))
+            .is_some_and(|format| format == self.format);
+        if matched {
+            return name.to_string();
+        }
+
+        let stem = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+        format!("{}.{}", stem, self.extension())
+    }
+
+    /// 将识别结果的坐标还原到原图坐标系
+    pub fn restore(&self, result: &mut ImageResult) {
+        if self.scale != 1.0 {
+            result.rescale(1.0 / self.scale);
+        }
+    }
+}
+
+/// 压缩时缩小图片的最小边长
+const MIN_SHRINK_SIDE: u32 = 16;
+
+/// 百度图片翻译接口支持直接上传的格式
+fn is_supported(format: ImageFormat) -> bool {
+    matches!(
+        format,
+        ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP
+    )
+}
+
+/// 预处理图片
+///
+/// - 格式支持且大小、尺寸都满足限制时原样返回
+/// - 尺寸超出限制时等比缩放
+/// - 不支持的格式转换为JPEG，体积超出限制时逐步降低JPEG质量和尺寸重新压缩
+pub fn prepare(data: &[u8], options: &PreprocessOptions) -> anyhow::Result<Prepared> {
+    if !(1..=100).contains(&options.jpeg_quality) {
+        anyhow::bail!("JPEG质量需要在1~100之间: {}", options.jpeg_quality);
+    }
+
+    let format = ::image::guess_format(data)?;
+    let img = ::image::load_from_memory_with_format(data, format)?;
+    let (width, height) = img.dimensions();
+
+    let mut scale = fit_scale(width, height, options);
+    if is_supported(format) && scale == 1.0 && data.len() <= options.max_bytes {
+        return Ok(Prepared {
+            data: data.to_vec(),
+            format,
+            scale,
+        });
+    }
+
+    // PNG优先保持无损，放不下再转JPEG
+    if format == ImageFormat::Png {
+        let encoded = encode(&resize(&img, scale), ImageFormat::Png, options.jpeg_quality)?;
+        if encoded.len() <= options.max_bytes {
+            return Ok(Prepared {
+                data: encoded,
+                format,
+                scale,
+            });
+        }
+    }
+
+    loop {
+        let resized = resize(&img, scale);
+        // 至少按设置的质量压缩一次，再逐步降低到50，设置的质量低于50时不再降低
+        let min_quality = options.jpeg_quality.min(50);
+        let mut quality = options.jpeg_quality;
+        loop {
+            let encoded = encode(&resized, ImageFormat::Jpeg, quality)?;
+            if encoded.len() <= options.max_bytes {
+                return Ok(Prepared {
+                    data: encoded,
+                    format: ImageFormat::Jpeg,
+                    scale,
+                });
+            }
+            if quality <= min_quality {
+                break;
+            }
+            quality = quality.saturating_sub(10).max(min_quality);
+        }
+
+        // `min_side`为0时依然需要一个下限，否则缩放会一直进行下去
+        scale *= 0.8;
+        let floor = options.min_side.max(MIN_SHRINK_SIDE) as f64;
+        if (width.min(height) as f64 * scale) < floor {
+            anyhow::bail!("图片无法压缩到{}字节以内", options.max_bytes);
+        }
+    }
+}
+
+/// 计算满足尺寸限制的缩放比例
+fn fit_scale(width: u32, height: u32, options: &PreprocessOptions) -> f64 {
+    let longest = width.max(height) as f64;
+    let shortest = width.min(height) as f64;
+
+    if longest > options.max_side as f64 {
+        options.max_side as f64 / longest
+    } else if shortest < options.min_side as f64 {
+        // 放大后最长边依然不能超出限制
+        (options.min_side as f64 / shortest).min(options.max_side as f64 / longest)
+    } else {
+        1.0
+    }
+}
+
+fn resize(img: &DynamicImage, scale: f64) -> DynamicImage {
+    if scale == 1.0 {
+        return img.clone();
+    }
+
+    let (width, height) = img.dimensions();
+    let width = ((width as f64 * scale).round() as u32).max(1);
+    let height = ((height as f64 * scale).round() as u32).max(1);
+    img.resize_exact(width, height, FilterType::Lanczos3)
+}
+
+fn encode(img: &DynamicImage, format: ImageFormat, quality: u8) -> anyhow::Result<Vec<u8>> {
+    let mut buf = Cursor::new(Vec::new());
+    match format {
+        ImageFormat::Jpeg => {
+            let encoder = ::image::codecs::jpeg::JpegEncoder::new_with_quality(&mut buf, quality);
+            // JPEG不支持透明通道
+            DynamicImage::ImageRgb8(img.to_rgb8()).write_with_encoder(encoder)?;
+        }
+        _ => img.write_to(&mut buf, format)?,
+    }
+
+    Ok(buf.into_inner())
+}
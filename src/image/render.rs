@@ -0,0 +1,362 @@
+//! 在原图上本地绘制译文
+//!
+//! 按[`Content::points`](super::model::Content::points)擦除原文区域，再用指定字体将译文绘制到该区域内，
+//! 整个过程不需要访问网络。
+//!
+//! 需要开启`render` feature
+//!
+//! ```toml
+//! baidu_trans = { version = "0.8.0", features = ["render"] }
+//! ```
+use ::image::{DynamicImage, Rgba, RgbaImage};
+use ab_glyph::{point, Font, FontVec, PxScale, ScaleFont};
+
+use super::model::{Content, ImageResult, Point};
+
+/// 译文渲染器
+pub struct Renderer {
+    font: FontVec,
+    color: Option<Rgba<u8>>,
+    fill: Option<Rgba<u8>>,
+    padding: f32,
+    min_size: f32,
+}
+
+impl Renderer {
+    /// 创建渲染器
+    /// - font: 字体文件数据(ttf/otf)，需要包含译文语种的字形
+    pub fn new(font: Vec<u8>) -> anyhow::Result<Self> {
+        Ok(Self {
+            font: FontVec::try_from_vec(font)?,
+            color: None,
+            fill: None,
+            padding: 1.0,
+            min_size: 6.0,
+        })
+    }
+
+    /// 设置文字颜色，默认根据背景亮度选择黑色或白色
+    pub fn color(mut self, color: [u8; 4]) -> Self {
+        self.color = Some(Rgba(color));
+        self
+    }
+
+    /// 设置擦除原文使用的填充色，默认取区域周围像素的平均色
+    pub fn fill(mut self, fill: [u8; 4]) -> Self {
+        self.fill = Some(Rgba(fill));
+        self
+    }
+
+    /// 设置文字与区域边界的间距，默认1像素
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// 设置最小字号，文字在最小字号下依然放不下时会超出区域，默认6像素
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// 解码原图并绘制译文
+    /// - original: 原图数据
+    /// - result: 原图的翻译结果
+    pub fn render(&self, original: &[u8], result: &ImageResult) -> anyhow::Result<RgbaImage> {
+        let image = ::image::load_from_memory(original)?;
+        Ok(self.render_image(&image, result))
+    }
+
+    /// 在原图上绘制译文
+    /// - image: 原图
+    /// - result: 原图的翻译结果
+    pub fn render_image(&self, image: &DynamicImage, result: &ImageResult) -> RgbaImage {
+        let mut canvas = image.to_rgba8();
+        // 空图片没有可以绘制的区域
+        if canvas.width() == 0 || canvas.height() == 0 {
+            return canvas;
+        }
+
+        for content in result.data.iter().flat_map(|data| data.content.iter()) {
+            self.render_content(&mut canvas, content);
+        }
+        canvas
+    }
+
+    fn render_content(&self, canvas: &mut RgbaImage, content: &Content) {
+        let Some(frame) = Frame::new(content) else {
+            return;
+        };
+
+        let fill = self
+            .fill
+            .unwrap_or_else(|| sample_background(canvas, &frame.polygon));
+        fill_polygon(canvas, &frame.polygon, fill);
+
+        let color = self.color.unwrap_or_else(|| contrast(fill));
+        self.draw_text(canvas, &frame, content.dst.trim(), color);
+    }
+
+    fn draw_text(&self, canvas: &mut RgbaImage, frame: &Frame, text: &str, color: Rgba<u8>) {
+        let width = (frame.width - self.padding * 2.0).max(1.0);
+        let height = (frame.height - self.padding * 2.0).max(1.0);
+
+        // 从区域高度开始逐步缩小字号，直到所有行都能放下
+        let mut size = height.min(256.0);
+        let mut lines = self.layout(text, PxScale::from(size), width);
+        while size > self.min_size {
+            let scaled = self.font.as_scaled(PxScale::from(size));
+            let fits = lines.len() as f32 * scaled.height() <= height
+                && lines
+                    .iter()
+                    .all(|line| self.measure(line, PxScale::from(size)) <= width);
+            if fits {
+                break;
+            }
+            size = (size * 0.9).max(self.min_size);
+            lines = self.layout(text, PxScale::from(size), width);
+        }
+
+        let scale = PxScale::from(size);
+        let scaled = self.font.as_scaled(scale);
+        let line_height = scaled.height();
+        let top = self.padding + (height - line_height * lines.len() as f32).max(0.0) / 2.0;
+
+        for (i, line) in lines.iter().enumerate() {
+            let mut caret = self.padding + (width - self.measure(line, scale)).max(0.0) / 2.0;
+            let baseline = top + line_height * i as f32 + scaled.ascent();
+            let mut prev = None;
+
+            for ch in line.chars() {
+                let id = self.font.glyph_id(ch);
+                if let Some(prev) = prev {
+                    caret += scaled.kern(prev, id);
+                }
+                prev = Some(id);
+
+                let glyph = id.with_scale_and_position(scale, point(caret, baseline));
+                caret += scaled.h_advance(id);
+
+                let Some(outline) = self.font.outline_glyph(glyph) else {
+                    continue;
+                };
+                let bounds = outline.px_bounds();
+                outline.draw(|gx, gy, coverage| {
+                    let (x, y) = frame.to_image(bounds.min.x + gx as f32, bounds.min.y + gy as f32);
+                    blend(canvas, x, y, color, coverage);
+                });
+            }
+        }
+    }
+
+    /// 按宽度换行，英文按单词换行，中日韩文字按字符换行
+    fn layout(&self, text: &str, scale: PxScale, width: f32) -> Vec<String> {
+        let mut lines = Vec::new();
+        for paragraph in text.lines() {
+            let mut line = String::new();
+            for token in tokenize(paragraph) {
+                let candidate = format!("{}{}", line, token);
+                if line.is_empty() || self.measure(&candidate, scale) <= width {
+                    line = candidate;
+                    continue;
+                }
+
+                lines.push(line.trim_end().to_string());
+                line = token.trim_start().to_string();
+            }
+            lines.push(line.trim_end().to_string());
+        }
+        lines
+    }
+
+    fn measure(&self, text: &str, scale: PxScale) -> f32 {
+        let scaled = self.font.as_scaled(scale);
+        let mut width = 0.0;
+        let mut prev = None;
+        for ch in text.chars() {
+            let id = self.font.glyph_id(ch);
+            if let Some(prev) = prev {
+                width += scaled.kern(prev, id);
+            }
+            width += scaled.h_advance(id);
+            prev = Some(id);
+        }
+        width
+    }
+}
+
+/// 译文区域的局部坐标系，原点为左上角，x轴沿上边，y轴垂直向下
+struct Frame {
+    polygon: Vec<(f32, f32)>,
+    origin: (f32, f32),
+    ex: (f32, f32),
+    ey: (f32, f32),
+    width: f32,
+    height: f32,
+}
+
+impl Frame {
+    fn new(content: &Content) -> Option<Frame> {
+        if let [p0, p1, _, p3, ..] = &content.points[..] {
+            let origin = to_f32(p0);
+            let (dx, dy) = sub(to_f32(p1), origin);
+            let width = dx.hypot(dy);
+            if width > 0.0 {
+                let ex = (dx / width, dy / width);
+                let ey = (-ex.1, ex.0);
+                let (hx, hy) = sub(to_f32(p3), origin);
+                let height = hx * ey.0 + hy * ey.1;
+                if height > 0.0 {
+                    return Some(Frame {
+                        polygon: content.points.iter().map(to_f32).collect(),
+                        origin,
+                        ex,
+                        ey,
+                        width,
+                        height,
+                    });
+                }
+            }
+        }
+
+        let rect = content.rect().ok().or_else(|| content.bounding_box())?;
+        if rect.width <= 0 || rect.height <= 0 {
+            return None;
+        }
+        let (left, top) = (rect.left as f32, rect.top as f32);
+        let (right, bottom) = (rect.right() as f32, rect.bottom() as f32);
+        Some(Frame {
+            polygon: vec![(left, top), (right, top), (right, bottom), (left, bottom)],
+            origin: (left, top),
+            ex: (1.0, 0.0),
+            ey: (0.0, 1.0),
+            width: rect.width as f32,
+            height: rect.height as f32,
+        })
+    }
+
+    fn to_image(&self, u: f32, v: f32) -> (f32, f32) {
+        (
+            self.origin.0 + u * self.ex.0 + v * self.ey.0,
+            self.origin.1 + u * self.ex.1 + v * self.ey.1,
+        )
+    }
+}
+
+fn to_f32(p: &Point) -> (f32, f32) {
+    (p.x as f32, p.y as f32)
+}
+
+fn sub(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+    (a.0 - b.0, a.1 - b.1)
+}
+
+/// 拆分为换行单元：连续的字母数字、单个空白或单个其他字符
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    for ch in text.chars() {
+        if ch.is_ascii_alphanumeric() || (ch.is_ascii_punctuation() && !word.is_empty()) {
+            word.push(ch);
+            continue;
+        }
+        if !word.is_empty() {
+            tokens.push(std::mem::take(&mut word));
+        }
+        tokens.push(ch.to_string());
+    }
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    tokens
+}
+
+/// 取多边形外接矩形周围一圈像素的平均色
+fn sample_background(canvas: &RgbaImage, polygon: &[(f32, f32)]) -> Rgba<u8> {
+    let (min_x, min_y, max_x, max_y) = bounds(canvas, polygon, 2);
+    let mut sum = [0u64; 4];
+    let mut count = 0u64;
+
+    for x in min_x..=max_x {
+        for y in [min_y, max_y] {
+            let pixel = canvas.get_pixel(x, y).0;
+            sum.iter_mut().zip(pixel).for_each(|(s, p)| *s += p as u64);
+            count += 1;
+        }
+    }
+    for y in min_y..=max_y {
+        for x in [min_x, max_x] {
+            let pixel = canvas.get_pixel(x, y).0;
+            sum.iter_mut().zip(pixel).for_each(|(s, p)| *s += p as u64);
+            count += 1;
+        }
+    }
+
+    Rgba(sum.map(|s| (s / count.max(1)) as u8))
+}
+
+/// 多边形外接矩形，向外扩展`margin`像素并限制在图片范围内
+fn bounds(canvas: &RgbaImage, polygon: &[(f32, f32)], margin: i64) -> (u32, u32, u32, u32) {
+    let clamp = |v: f32, max: u32| (v as i64).clamp(0, max as i64 - 1) as u32;
+    let min_x = polygon.iter().map(|p| p.0).fold(f32::MAX, f32::min) - margin as f32;
+    let min_y = polygon.iter().map(|p| p.1).fold(f32::MAX, f32::min) - margin as f32;
+    let max_x = polygon.iter().map(|p| p.0).fold(f32::MIN, f32::max) + margin as f32;
+    let max_y = polygon.iter().map(|p| p.1).fold(f32::MIN, f32::max) + margin as f32;
+
+    (
+        clamp(min_x, canvas.width()),
+        clamp(min_y, canvas.height()),
+        clamp(max_x, canvas.width()),
+        clamp(max_y, canvas.height()),
+    )
+}
+
+/// 扫描线填充多边形
+fn fill_polygon(canvas: &mut RgbaImage, polygon: &[(f32, f32)], color: Rgba<u8>) {
+    let (min_x, min_y, max_x, max_y) = bounds(canvas, polygon, 1);
+
+    for y in min_y..=max_y {
+        let cy = y as f32 + 0.5;
+        let mut xs: Vec<f32> = polygon
+            .iter()
+            .zip(polygon.iter().cycle().skip(1))
+            .filter(|(a, b)| (a.1 <= cy) != (b.1 <= cy))
+            .map(|(a, b)| a.0 + (cy - a.1) / (b.1 - a.1) * (b.0 - a.0))
+            .collect();
+        xs.sort_by(|a, b| a.total_cmp(b));
+
+        for pair in xs.chunks_exact(2) {
+            let start = (pair[0].round().max(min_x as f32)) as u32;
+            let end = (pair[1].round().min(max_x as f32 + 1.0)) as u32;
+            for x in start..end {
+                canvas.put_pixel(x, y, color);
+            }
+        }
+    }
+}
+
+/// 按背景亮度选择黑色或白色文字
+fn contrast(background: Rgba<u8>) -> Rgba<u8> {
+    let [r, g, b, _] = background.0;
+    let luminance = 0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32;
+    if luminance > 128.0 {
+        Rgba([0, 0, 0, 255])
+    } else {
+        Rgba([255, 255, 255, 255])
+    }
+}
+
+fn blend(canvas: &mut RgbaImage, x: f32, y: f32, color: Rgba<u8>, coverage: f32) {
+    let (x, y) = (x.round(), y.round());
+    if x < 0.0 || y < 0.0 || x >= canvas.width() as f32 || y >= canvas.height() as f32 {
+        return;
+    }
+
+    let pixel = canvas.get_pixel_mut(x as u32, y as u32);
+    let coverage = coverage.clamp(0.0, 1.0);
+    for i in 0..3 {
+        pixel.0[i] =
+            (color.0[i] as f32 * coverage + pixel.0[i] as f32 * (1.0 - coverage)).round() as u8;
+    }
+    pixel.0[3] = pixel.0[3].max((color.0[3] as f32 * coverage) as u8);
+}
@@ -5,18 +5,24 @@
 //! - [x] 图片翻译
 //! - [x] 垂直领域翻译
 //! - [x] 文档翻译
+//! - [x] 语音翻译
+//! - [x] 百度智能云文本翻译
+//! - [x] 大模型文本翻译
+//! - [x] 客户端术语表
 //!
 //! 引入依赖:
 //!
 //! ```toml
 //! [dependencies]
-//! baidu_trans =  { version = "0.7.5", features = [] }
+//! baidu_trans =  { version = "0.8.0", features = [] }
 //! ```
 //!
 //! 如果要使用`async/await`，需要添加`aio` features。
 //!
 //! 如果要支持图片翻译，需要添加`image` feature。
 //!
+//! 如果要序列化返回结果(存库、缓存等)，需要添加`serde` feature。
+//!
 //! # 基本用法
 //!
 //! ## 使用阻塞的方式。
@@ -26,7 +32,7 @@
 //! ```toml
 //! [dependencies]
 //! anyhow = "1.0.66"
-//! baidu_trans =  { version = "0.7.5", features = [] }
+//! baidu_trans =  { version = "0.8.0", features = [] }
 //! dotenv = "0.15.0"
 //! ```
 //!
@@ -64,7 +70,7 @@
  ```toml
  [dependencies]
  anyhow = "1.0.66"
- baidu_trans =  { version = "0.7.5", features = ["aio"] }
+ baidu_trans =  { version = "0.8.0", features = ["aio"] }
  tokio = { version = "1.21.2", features = ["full"] }
  dotenv = "0.15.0"
  ```
@@ -106,12 +112,13 @@
 需要启用`image` feature.
 ```toml
 [dependencies]
-baidu_trans =  { version = "0.7.5", features = ["image"] }
+baidu_trans =  { version = "0.8.0", features = ["image"] }
 ```
 使用方式
 ```rust,no_run
 use baidu_trans::blocking::Client;
 use baidu_trans::config::Config;
+use baidu_trans::image::{ImageRequest, PasteMode};
 use baidu_trans::lang::Lang;
 use std::fs;
 
@@ -121,10 +128,15 @@ fn main() -> anyhow::Result<()> {
     let app_secret = dotenv::var("APP_SECRET")?;
     let client = Client::new(Config::new(app_id, app_secret));
     client.lang(Lang::Auto, Lang::Zh);
+    // 返回整图贴合图片
+    client.image_request(ImageRequest::default().paste(PasteMode::FullImage));
     let data = fs::read("a.png")?;
     /// 图片名称必须填写
     let resp = client.image_translate("a.png", data)?;
     assert_eq!(resp.error_code, "0");
+    if let Some(img) = resp.data.unwrap().paste_image()? {
+        fs::write("a.paste.jpg", img)?;
+    }
     Ok(())
 }
 ```
@@ -140,7 +152,7 @@ fn main() -> anyhow::Result<()> {
 
  ```toml
  [dependencies]
- baidu_trans =  { version = "0.7.5", features = ["domain"] }
+ baidu_trans =  { version = "0.8.0", features = ["domain"] }
 
  ```
 
@@ -181,6 +193,8 @@ automatically introduces overhead thanks to the necessary heap pointer.",
 
 #[cfg(feature = "aio")]
 pub mod aio;
+#[cfg(feature = "bce")]
+pub mod bce;
 #[cfg(feature = "blocking")]
 pub mod blocking;
 pub mod config;
@@ -189,8 +203,16 @@ pub mod constant;
 pub mod document;
 #[cfg(feature = "domain")]
 pub mod domain;
+#[cfg(feature = "glossary")]
+pub mod glossary;
 #[cfg(feature = "image")]
 pub mod image;
 pub mod lang;
+#[cfg(feature = "llm")]
+pub mod llm;
 pub mod model;
+pub mod response;
+#[cfg(any(feature = "blocking", feature = "aio", feature = "callback"))]
 pub mod util;
+#[cfg(feature = "voice")]
+pub mod voice;
@@ -0,0 +1,101 @@
+//! 大模型文本翻译
+//!
+//! 百度翻译开放平台基于大模型的文本翻译接口，可以额外提供参考文本（例如风格、语气、
+//! 背景说明），并支持保留HTML/XML标签，适合营销文案等需要润色的场景。
+//! 签名方式与通用翻译相同，使用[`Config`](crate::config::Config)中的`app_id`和`secret_key`，
+//! 返回结果可以转换为[`CommonResult`](crate::model::CommonResult)，方便与通用翻译对比。
+//!
+//! 具体使用请查看: [https://fanyi-api.baidu.com/product/13]
+//!
+//! 需要开启`llm` feature
+//!
+//! ```toml
+//! baidu_trans = { version = "0.8.0", features = ["llm"] }
+//! ```
+use std::{fmt::Display, str::FromStr};
+
+pub mod model;
+
+/// 翻译模型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModelType {
+    /// 大模型翻译
+    #[default]
+    Llm,
+    /// 机器翻译
+    Nmt,
+}
+
+impl Display for ModelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            ModelType::Llm => "llm",
+            ModelType::Nmt => "nmt",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for ModelType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "llm" => Ok(ModelType::Llm),
+            "nmt" => Ok(ModelType::Nmt),
+            _ => anyhow::bail!("未知的翻译模型: {}", s),
+        }
+    }
+}
+
+/// 大模型翻译请求参数
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LlmRequest {
+    /// 翻译模型，默认大模型
+    pub model_type: ModelType,
+    /// 参考文本，可以描述译文风格、语气或者提供背景信息
+    pub reference: Option<String>,
+    /// 是否保留原文中的HTML/XML标签，默认否
+    pub tag_handling: bool,
+    /// 开启标签处理时，不翻译这些标签内的文本
+    pub ignore_tags: Vec<String>,
+    /// 是否使用"我的术语"干预，默认否
+    pub need_intervene: bool,
+}
+
+impl LlmRequest {
+    /// 设置翻译模型
+    pub fn model_type(mut self, model_type: ModelType) -> Self {
+        self.model_type = model_type;
+        self
+    }
+
+    /// 设置参考文本
+    pub fn reference(mut self, reference: impl Into<String>) -> Self {
+        self.reference = Some(reference.into());
+        self
+    }
+
+    /// 保留原文中的标签
+    pub fn tag_handling(mut self, tag_handling: bool) -> Self {
+        self.tag_handling = tag_handling;
+        self
+    }
+
+    /// 设置不翻译的标签，同时开启标签处理
+    pub fn ignore_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.ignore_tags = tags.into_iter().map(Into::into).collect();
+        self.tag_handling = true;
+        self
+    }
+
+    /// 使用"我的术语"干预
+    pub fn need_intervene(mut self, need_intervene: bool) -> Self {
+        self.need_intervene = need_intervene;
+        self
+    }
+}
@@ -0,0 +1,50 @@
+//! 大模型翻译返回相关结构
+
+use serde::Deserialize;
+
+use crate::model::{CommonResult, ErrorCode, TranslateResult};
+
+/// 大模型翻译返回结果
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LlmResult {
+    /// 源语言
+    pub from: Option<String>,
+    /// 目标语言
+    pub to: Option<String>,
+    /// 翻译结果
+    pub trans_result: Option<Vec<TranslateResult>>,
+    /// 错误码
+    pub error_code: Option<ErrorCode>,
+    /// 错误消息
+    pub error_msg: Option<String>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl LlmResult {
+    /// 拼接所有段落的译文，段落之间用换行分隔，与[`CommonResult::dst_text`]一致
+    pub fn dst_text(&self) -> String {
+        self.trans_result
+            .iter()
+            .flatten()
+            .map(|r| r.dst.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl From<LlmResult> for CommonResult {
+    fn from(result: LlmResult) -> Self {
+        CommonResult {
+            from: result.from,
+            to: result.to,
+            trans_result: result.trans_result,
+            error_code: result.error_code,
+            error_msg: result.error_msg,
+            extra: result.extra,
+            ..Default::default()
+        }
+    }
+}
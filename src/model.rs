@@ -1,10 +1,13 @@
 //! 通用翻译相关模块
 
-use serde::Deserialize;
+use std::fmt::Display;
+
+use serde::{de, de::DeserializeOwned, Deserialize, Deserializer};
 
 /// 通用翻译返回结构
 #[allow(dead_code)]
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CommonResult {
     /// 源语言
     pub from: Option<String>,
@@ -13,7 +16,7 @@ pub struct CommonResult {
     /// 翻译结果
     pub trans_result: Option<Vec<TranslateResult>>,
     /// 错误码
-    pub error_code: Option<String>,
+    pub error_code: Option<ErrorCode>,
     /// 错误消息
     pub error_msg: Option<String>,
     /// 原文tts链接，mp3格式，暂时无法指定发音
@@ -22,13 +25,402 @@ pub struct CommonResult {
     pub dst_tts: Option<String>,
     /// 中英词典资源，返回中文或英文词典资源，包含音标；简明释义等内容
     pub dict: Option<String>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// 翻译返回的结果
 #[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TranslateResult {
     /// 源文本
     pub src: String,
     /// 翻译后的文本
     pub dst: String,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 错误码
+///
+/// 百度各接口返回的错误码类型并不统一：同一个接口成功时可能返回整型，失败时返回字符串，
+/// 这里同时兼容整型和字符串，序列化时保持原来的类型。
+#[derive(Debug, Clone, Default, Eq)]
+pub struct ErrorCode {
+    code: String,
+    numeric: bool,
+}
+
+impl ErrorCode {
+    /// 数字错误码，无法解析为数字时返回`None`
+    pub fn code(&self) -> Option<i64> {
+        self.code.trim().parse().ok()
+    }
+
+    /// 原始错误码字符串
+    pub fn as_str(&self) -> &str {
+        &self.code
+    }
+
+    /// 接口返回的错误码是否为整型
+    pub fn is_numeric(&self) -> bool {
+        self.numeric
+    }
+
+    /// 是否表示成功，`0`或`52000`
+    pub fn is_success(&self) -> bool {
+        matches!(self.code(), Some(0 | 52000))
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+impl From<i64> for ErrorCode {
+    fn from(code: i64) -> Self {
+        Self {
+            code: code.to_string(),
+            numeric: true,
+        }
+    }
+}
+
+impl From<&str> for ErrorCode {
+    fn from(code: &str) -> Self {
+        Self {
+            code: code.to_string(),
+            numeric: false,
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(code: String) -> Self {
+        Self {
+            code,
+            numeric: false,
+        }
+    }
+}
+
+impl PartialEq for ErrorCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.code == other.code
+    }
+}
+
+impl std::hash::Hash for ErrorCode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.code.hash(state);
+    }
+}
+
+impl PartialEq<str> for ErrorCode {
+    fn eq(&self, other: &str) -> bool {
+        self.code == other
+    }
+}
+
+impl PartialEq<&str> for ErrorCode {
+    fn eq(&self, other: &&str) -> bool {
+        self.code == *other
+    }
+}
+
+impl PartialEq<i64> for ErrorCode {
+    fn eq(&self, other: &i64) -> bool {
+        self.code() == Some(*other)
+    }
+}
+
+impl PartialEq<i32> for ErrorCode {
+    fn eq(&self, other: &i32) -> bool {
+        self.code() == Some(i64::from(*other))
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ErrorCodeVisitor;
+
+        impl<'de> de::Visitor<'de> for ErrorCodeVisitor {
+            type Value = ErrorCode;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an integer or a string error code")
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(v.into())
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(ErrorCode {
+                    code: v.to_string(),
+                    numeric: true,
+                })
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Ok(v.into())
+            }
+
+            fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+                Ok(v.into())
+            }
+        }
+
+        deserializer.deserialize_any(ErrorCodeVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ErrorCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.code() {
+            Some(code) if self.numeric => serializer.serialize_i64(code),
+            _ => serializer.serialize_str(&self.code),
+        }
+    }
+}
+
+impl CommonResult {
+    /// 拼接所有分段的原文
+    pub fn src_text(&self) -> String {
+        self.join_result(|t| &t.src)
+    }
+
+    /// 拼接所有分段的译文
+    pub fn dst_text(&self) -> String {
+        self.join_result(|t| &t.dst)
+    }
+
+    fn join_result(&self, f: impl Fn(&TranslateResult) -> &String) -> String {
+        self.trans_result
+            .iter()
+            .flatten()
+            .map(f)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// 解析词典资源，需要开通词典(`open_dict`)
+    ///
+    /// 未返回词典资源时返回`Ok(None)`
+    pub fn dictionary(&self) -> anyhow::Result<Option<Dictionary>> {
+        match self.dict.as_deref() {
+            Some(dict) if !dict.trim().is_empty() => Ok(Some(serde_json::from_str(dict)?)),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// 中英词典资源
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Dictionary {
+    /// 词典结果
+    #[serde(default)]
+    pub word_result: WordResult,
+    /// 词典语种
+    #[serde(default)]
+    pub lang: Option<String>,
+}
+
+/// 词典结果
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct WordResult {
+    /// 简明释义
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub simple_means: Option<SimpleMeans>,
+    /// 英英词典，包含例句、近义词
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub edict: Option<Edict>,
+}
+
+/// 简明释义
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SimpleMeans {
+    /// 单词
+    #[serde(default)]
+    pub word_name: String,
+    /// 释义列表
+    #[serde(default)]
+    pub word_means: Vec<String>,
+    /// 音标及词性
+    #[serde(default)]
+    pub symbols: Vec<Symbol>,
+}
+
+/// 音标及词性
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Symbol {
+    /// 英式音标
+    #[serde(default)]
+    pub ph_en: Option<String>,
+    /// 美式音标
+    #[serde(default)]
+    pub ph_am: Option<String>,
+    /// 拼音，中文词条有效
+    #[serde(default)]
+    pub word_symbol: Option<String>,
+    /// 词性及释义
+    #[serde(default)]
+    pub parts: Vec<Part>,
+}
+
+/// 词性及释义
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Part {
+    /// 词性，如`n.`、`vt.`，中文词条可能为空
+    #[serde(default, alias = "part_name")]
+    pub part: String,
+    /// 释义
+    #[serde(default, deserialize_with = "means_as_strings")]
+    pub means: Vec<String>,
+}
+
+/// 英英词典
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Edict {
+    /// 单词
+    #[serde(default)]
+    pub word: String,
+    /// 按词性分组的释义
+    #[serde(default)]
+    pub item: Vec<EdictItem>,
+}
+
+/// 英英词典词性分组
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EdictItem {
+    /// 词性
+    #[serde(default)]
+    pub pos: String,
+    /// 释义组
+    #[serde(default)]
+    pub tr_group: Vec<TrGroup>,
+}
+
+/// 英英词典释义组
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TrGroup {
+    /// 释义
+    #[serde(default)]
+    pub tr: Vec<String>,
+    /// 例句
+    #[serde(default)]
+    pub example: Vec<String>,
+    /// 近义词
+    #[serde(default)]
+    pub similar_word: Vec<String>,
+}
+
+impl Dictionary {
+    fn symbol(&self) -> Option<&Symbol> {
+        self.word_result
+            .simple_means
+            .as_ref()
+            .and_then(|means| means.symbols.first())
+    }
+
+    /// 英式音标
+    pub fn ph_en(&self) -> Option<&str> {
+        self.symbol().and_then(|s| s.ph_en.as_deref())
+    }
+
+    /// 美式音标
+    pub fn ph_am(&self) -> Option<&str> {
+        self.symbol().and_then(|s| s.ph_am.as_deref())
+    }
+
+    /// 词性及释义
+    pub fn parts(&self) -> &[Part] {
+        self.symbol()
+            .map(|s| s.parts.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 简明释义
+    pub fn means(&self) -> &[String] {
+        self.word_result
+            .simple_means
+            .as_ref()
+            .map(|means| means.word_means.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 所有例句
+    pub fn examples(&self) -> Vec<&str> {
+        self.tr_groups()
+            .flat_map(|group| group.example.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// 所有近义词
+    pub fn synonyms(&self) -> Vec<&str> {
+        self.tr_groups()
+            .flat_map(|group| group.similar_word.iter().map(String::as_str))
+            .collect()
+    }
+
+    fn tr_groups(&self) -> impl Iterator<Item = &TrGroup> {
+        self.word_result
+            .edict
+            .iter()
+            .flat_map(|edict| edict.item.iter())
+            .flat_map(|item| item.tr_group.iter())
+    }
+}
+
+/// 百度在没有对应资源时会返回空字符串而不是`null`
+fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Null => Ok(None),
+        serde_json::Value::String(s) if s.is_empty() => Ok(None),
+        value => T::deserialize(value).map(Some).map_err(de::Error::custom),
+    }
+}
+
+/// 英文词条的释义是字符串，中文词条的释义是包含`word_mean`或`text`的对象
+fn means_as_strings<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let values = Vec::<serde_json::Value>::deserialize(deserializer)?;
+    Ok(values
+        .into_iter()
+        .filter_map(|value| match value {
+            serde_json::Value::String(s) => Some(s),
+            serde_json::Value::Object(obj) => obj
+                .get("word_mean")
+                .or_else(|| obj.get("text"))
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            _ => None,
+        })
+        .collect())
 }
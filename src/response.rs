@@ -0,0 +1,120 @@
+//! 原始响应相关模块
+use std::{borrow::Cow, fmt::Display, time::Duration};
+
+use reqwest::{header::HeaderMap, StatusCode};
+use serde::de::DeserializeOwned;
+
+/// 带原始响应信息的返回结果，由各个`*_raw`方法返回
+///
+/// 以下方法没有对应的`*_raw`版本：
+/// - `domain_translate_fallback`：回退时可能发出多次请求，没有唯一的原始响应，
+///   可以改用`domain_translate_raw`和`translate_raw`自行组合
+/// - `translate_document`、`translate_document_quoted`：包含提交、轮询和下载多次请求，返回的是译文路径，
+///   需要原始响应时使用`doc_translate_file_raw`和`doc_query_raw`
+/// - `tts`及相关方法：返回的是音频数据而不是JSON，没有可解析的返回结果
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    /// HTTP状态码
+    pub status: StatusCode,
+    /// 响应头
+    pub headers: HeaderMap,
+    /// 原始响应体
+    pub body: Vec<u8>,
+    /// 请求耗时
+    pub elapsed: Duration,
+    /// 解析后的返回结果
+    pub data: T,
+}
+
+impl<T> Response<T> {
+    /// 取出解析后的返回结果
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// 响应体文本
+    pub fn text(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.body)
+    }
+
+    /// 转换返回结果，保留原始响应信息
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Response<U> {
+        Response {
+            status: self.status,
+            headers: self.headers,
+            body: self.body,
+            elapsed: self.elapsed,
+            data: f(self.data),
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Response<T> {
+    /// 解析响应体
+    #[cfg(any(feature = "blocking", feature = "aio"))]
+    pub(crate) fn decode(
+        status: StatusCode,
+        headers: HeaderMap,
+        body: Vec<u8>,
+        elapsed: Duration,
+    ) -> anyhow::Result<Self> {
+        match serde_json::from_slice(&body) {
+            Ok(data) => Ok(Self {
+                status,
+                headers,
+                body,
+                elapsed,
+                data,
+            }),
+            Err(source) => Err(DecodeError {
+                status,
+                headers,
+                body,
+                elapsed,
+                source,
+            }
+            .into()),
+        }
+    }
+}
+
+/// 解析响应失败，保留了原始响应信息
+///
+/// 可以通过`anyhow::Error::downcast_ref::<DecodeError>()`获取
+#[derive(Debug)]
+pub struct DecodeError {
+    /// HTTP状态码
+    pub status: StatusCode,
+    /// 响应头
+    pub headers: HeaderMap,
+    /// 原始响应体
+    pub body: Vec<u8>,
+    /// 请求耗时
+    pub elapsed: Duration,
+    source: serde_json::Error,
+}
+
+impl DecodeError {
+    /// 响应体文本
+    pub fn text(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.body)
+    }
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "解析响应失败(HTTP {}): {}, 响应体: {}",
+            self.status,
+            self.source,
+            self.text()
+        )
+    }
+}
+
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -1,10 +1,12 @@
 //! 相关辅助函数
 use std::collections::HashMap;
+#[cfg(any(feature = "blocking", feature = "aio"))]
+use std::path::{Path, PathBuf};
 
 use chrono::Local;
 use md5::{Digest, Md5};
 
-use crate::config::Config;
+use crate::{config::Config, lang::Lang};
 
 /// 构建表单参数
 macro_rules! build_params {
@@ -33,12 +35,20 @@ macro_rules! md5_encode {
 /// 构建通用翻译表单
 /// - config: 客户端配置
 /// - q: 待翻译的文本
-pub(crate) fn build_form(config: &Config, q: &str) -> HashMap<String, String> {
+/// - from: 源语言
+/// - to: 目标语言
+#[cfg(any(feature = "blocking", feature = "aio"))]
+pub(crate) fn build_form(
+    config: &Config,
+    q: &str,
+    from: Lang,
+    to: Lang,
+) -> HashMap<String, String> {
     let salt = Local::now().timestamp();
     let mut params = build_params! {
         ("q", q),
-        ("from", &config.from),
-        ("to", &config.to),
+        ("from", from),
+        ("to", to),
         ("appid", config.app_id),
         ("salt", salt)
     };
@@ -61,124 +71,307 @@ pub(crate) fn build_form(config: &Config, q: &str) -> HashMap<String, String> {
     params
 }
 
-/// 构建图片翻译表单参数, 需要开启`aio`和`image` features。
+/// TTS音频缓存文件路径，按文本和语种区分
+/// - dir: 缓存目录
+/// - text: 发音文本
+/// - lang: 发音语种
+#[cfg(any(feature = "blocking", feature = "aio"))]
+pub(crate) fn tts_cache_path(dir: &Path, text: &str, lang: &str) -> PathBuf {
+    dir.join(format!("{}.mp3", md5_encode!(lang, "\n", text)))
+}
+
+/// 同目录下唯一的临时文件路径，用于先写入再重命名
+/// - path: 最终的文件路径
+#[cfg(any(feature = "blocking", feature = "aio"))]
+pub(crate) fn temp_path(path: &Path) -> PathBuf {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(
+        ".{}.{}.tmp",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    path.with_file_name(name)
+}
+
+/// 共享的内存数据，多个reader读取同一份数据而不需要复制
+#[cfg(feature = "doc")]
+#[derive(Clone)]
+pub(crate) struct SharedBuf(std::sync::Arc<Vec<u8>>);
+
+#[cfg(feature = "doc")]
+impl SharedBuf {
+    pub(crate) fn new(data: Vec<u8>) -> Self {
+        Self(std::sync::Arc::new(data))
+    }
+
+    /// 从头读取数据的reader
+    pub(crate) fn reader(&self) -> std::io::Cursor<SharedBuf> {
+        std::io::Cursor::new(self.clone())
+    }
+}
+
+#[cfg(feature = "doc")]
+impl AsRef<[u8]> for SharedBuf {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// 计算数据的md5
+#[cfg(any(feature = "image", feature = "doc"))]
+pub(crate) fn md5_hex(data: &[u8]) -> String {
+    md5_encode!(data)
+}
+
+/// 增量计算md5，不需要将数据全部读入内存
+#[cfg(all(feature = "blocking", any(feature = "image", feature = "doc")))]
+pub(crate) fn md5_reader(reader: &mut impl std::io::Read) -> std::io::Result<String> {
+    let mut hasher = Md5::new();
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// 增量计算md5，不需要将数据全部读入内存
+#[cfg(all(feature = "aio", any(feature = "image", feature = "doc")))]
+pub(crate) async fn md5_async_reader(
+    reader: &mut (impl tokio::io::AsyncRead + Unpin),
+) -> std::io::Result<String> {
+    use tokio::io::AsyncReadExt;
+
+    let mut hasher = Md5::new();
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let n = reader.read(&mut buf).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// 从reader创建流式上传的文件表单，返回表单和数据的md5
+///
+/// 签名需要数据的md5，所以会先读取一遍计算md5，再回到开头上传，
+/// 只上传reader当前位置到结尾的数据
 /// - name: 文件名
-/// - data: 图片数据
-/// - config: 客户端配置
-/// - cuid: 固定值：APICUID
-/// - mac: 固定值：mac
-/// - version: 固定值：3
-/// - paste: 图片贴合类型：0 - 关闭文字贴合 、1 - 返回整图贴合 、2 - 返回块区贴合
+/// - reader: 文件数据
+#[cfg(all(feature = "blocking", any(feature = "image", feature = "doc")))]
+pub(crate) fn reader_part_blocking<R>(
+    name: &str,
+    mut reader: R,
+) -> std::io::Result<(reqwest::blocking::multipart::Part, String)>
+where
+    R: std::io::Read + std::io::Seek + Send + 'static,
+{
+    use std::io::SeekFrom;
+
+    let start = reader.stream_position()?;
+    let md5 = md5_reader(&mut reader)?;
+    let len = reader.stream_position()? - start;
+    reader.seek(SeekFrom::Start(start))?;
+
+    let part = reqwest::blocking::multipart::Part::reader_with_length(reader.take(len), len)
+        .file_name(name.to_string());
+    Ok((part, md5))
+}
+
+/// 从reader创建流式上传的文件表单，返回表单和数据的md5
+///
+/// 签名需要数据的md5，所以会先读取一遍计算md5，再回到开头上传，
+/// 只上传reader当前位置到结尾的数据
+/// - name: 文件名
+/// - reader: 文件数据
+#[cfg(all(feature = "aio", any(feature = "image", feature = "doc")))]
+pub(crate) async fn reader_part_aio<R>(
+    name: &str,
+    mut reader: R,
+) -> std::io::Result<(reqwest::multipart::Part, String)>
+where
+    R: tokio::io::AsyncRead + tokio::io::AsyncSeek + Send + Sync + Unpin + 'static,
+{
+    use std::io::SeekFrom;
+
+    use reqwest::{multipart::Part, Body};
+    use tokio::io::{AsyncReadExt, AsyncSeekExt};
+    use tokio_util::io::ReaderStream;
+
+    let start = reader.stream_position().await?;
+    let md5 = md5_async_reader(&mut reader).await?;
+    let len = reader.stream_position().await? - start;
+    reader.seek(SeekFrom::Start(start)).await?;
+
+    let body = Body::wrap_stream(ReaderStream::new(reader.take(len)));
+    let part = Part::stream_with_length(body, len).file_name(name.to_string());
+    Ok((part, md5))
+}
+
+/// 遍历目录，返回匹配的文件路径(已排序)
+/// - dir: 目录
+/// - include: 包含的文件，glob模式，匹配相对于`dir`的路径
+/// - exclude: 排除的文件，glob模式，匹配相对于`dir`的路径
+/// - recursive: 是否遍历子目录
+#[cfg(feature = "batch")]
+pub(crate) fn walk_files(
+    dir: &Path,
+    include: &[String],
+    exclude: &[String],
+    recursive: bool,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let compile = |patterns: &[String]| {
+        patterns
+            .iter()
+            .map(|p| glob::Pattern::new(p))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let include = compile(include)?;
+    let exclude = compile(exclude)?;
+    let options = glob::MatchOptions {
+        case_sensitive: false,
+        ..Default::default()
+    };
+
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(current) = dirs.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                if recursive {
+                    dirs.push(path);
+                }
+                continue;
+            }
+
+            let relative = path.strip_prefix(dir).unwrap_or(&path);
+            let matches = |patterns: &[glob::Pattern]| {
+                patterns
+                    .iter()
+                    .any(|p| p.matches_path_with(relative, options))
+            };
+            if matches(&include) && !matches(&exclude) {
+                files.push(path);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+/// 构建图片翻译表单参数, 需要开启`aio`和`image` features。
+/// - image: 图片表单项
+/// - image_md5: 图片数据的md5
+/// - from: 源语言
+/// - to: 目标语言
+/// - config: 客户端配置，`cuid`、`mac`、`version`、`paste`取自[`Config::image`]
 #[cfg(all(feature = "aio", feature = "image"))]
 pub(crate) fn create_image_form(
-    name: &str,
-    data: Vec<u8>,
+    image: reqwest::multipart::Part,
+    image_md5: &str,
+    from: Lang,
+    to: Lang,
     config: &Config,
-    cuid: &str,
-    mac: &str,
-    version: &str,
-    paste: Option<String>,
 ) -> reqwest::multipart::Form {
     use reqwest::multipart;
 
+    let request = &config.image;
     let salt = Local::now().timestamp();
     let sign = md5_encode!(
         &config.app_id,
-        md5_encode!(&data),
+        image_md5,
         salt.to_string(),
-        cuid,
-        mac,
+        &request.cuid,
+        &request.mac,
         &config.secret_key,
     );
 
-    let params = multipart::Form::new()
-        .part(
-            "image",
-            multipart::Part::bytes(data).file_name(name.to_string()),
-        )
-        .text("from", config.from.to_string())
-        .text("to", config.to.to_string())
+    multipart::Form::new()
+        .part("image", image)
+        .text("from", from.to_string())
+        .text("to", to.to_string())
         .text("appid", config.app_id.clone())
         .text("salt", salt.to_string())
-        .text("cuid", cuid.to_string())
-        .text("mac", mac.to_string())
-        .text("version", version.to_string())
-        .text("sign", sign);
-
-    if let Some(paste) = paste {
-        params.text("paste", paste)
-    } else {
-        params
-    }
+        .text("cuid", request.cuid.clone())
+        .text("mac", request.mac.clone())
+        .text("version", request.version.clone())
+        .text("paste", request.paste.to_string())
+        .text("sign", sign)
 }
 
 /// 构建图片翻译表单参数，需要开启`blocking`和`image` features。
-/// - name: 文件名
-/// - data: 图片数据
-/// - config: 客户端配置
-/// - cuid: 固定值：APICUID
-/// - mac: 固定值：mac
-/// - version: 固定值：3
-/// - paste: 图片贴合类型：0 - 关闭文字贴合 、1 - 返回整图贴合 、2 - 返回块区贴合
+/// - image: 图片表单项
+/// - image_md5: 图片数据的md5
+/// - from: 源语言
+/// - to: 目标语言
+/// - config: 客户端配置，`cuid`、`mac`、`version`、`paste`取自[`Config::image`]
 #[cfg(all(feature = "blocking", feature = "image"))]
 pub(crate) fn create_image_form_blocking(
-    name: &str,
-    data: Vec<u8>,
+    image: reqwest::blocking::multipart::Part,
+    image_md5: &str,
+    from: Lang,
+    to: Lang,
     config: &Config,
-    cuid: &str,
-    mac: &str,
-    version: &str,
-    paste: Option<String>,
 ) -> reqwest::blocking::multipart::Form {
     use reqwest::blocking::multipart;
 
+    let request = &config.image;
     let salt = Local::now().timestamp();
     let sign = md5_encode!(
         &config.app_id,
-        md5_encode!(&data),
+        image_md5,
         salt.to_string(),
-        cuid,
-        mac,
+        &request.cuid,
+        &request.mac,
         &config.secret_key
     );
 
-    let params = multipart::Form::new()
-        .part(
-            "image",
-            multipart::Part::bytes(data).file_name(name.to_string()),
-        )
-        .text("from", config.from.to_string())
-        .text("to", config.to.to_string())
+    multipart::Form::new()
+        .part("image", image)
+        .text("from", from.to_string())
+        .text("to", to.to_string())
         .text("appid", config.app_id.clone())
         .text("salt", salt.to_string())
-        .text("cuid", cuid.to_string())
-        .text("mac", mac.to_string())
-        .text("version", version.to_string())
-        .text("sign", sign);
-
-    if let Some(paste) = paste {
-        params.text("paste", paste)
-    } else {
-        params
-    }
+        .text("cuid", request.cuid.clone())
+        .text("mac", request.mac.clone())
+        .text("version", request.version.clone())
+        .text("paste", request.paste.to_string())
+        .text("sign", sign)
 }
 
 /// 构建垂直领域翻译表单
 /// - config: 客户端配置
 /// - q: 待翻译的文本
 /// - domain: 所选择的垂直领域
+/// - from: 源语言
+/// - to: 目标语言
 #[cfg(feature = "domain")]
 pub(crate) fn build_domain_form(
     config: &Config,
     q: &str,
     domain: crate::domain::Domain,
+    from: Lang,
+    to: Lang,
 ) -> HashMap<String, String> {
     let mut params = HashMap::new();
 
     let salt = Local::now().timestamp();
     params.insert("q".into(), q.into());
-    params.insert("from".into(), config.from.to_string());
-    params.insert("to".into(), config.to.to_string());
+    params.insert("from".into(), from.to_string());
+    params.insert("to".into(), to.to_string());
     params.insert("appid".into(), config.app_id.clone());
     params.insert("salt".into(), salt.to_string());
     params.insert("domain".into(), domain.to_string());
@@ -195,71 +388,97 @@ pub(crate) fn build_domain_form(
     params
 }
 
-/// 构建文档翻译统计校验服务表单
-#[cfg(all(feature = "blocking", feature = "doc"))]
-pub(crate) fn build_doc_count_form_blocking(
+/// 构建语音翻译请求，返回请求头和JSON请求体
+///
+/// 签名为`base64(hmac_sha256(密钥, appid + timestamp + 语音base64))`，放在`X-Sign`请求头中
+/// - config: 客户端配置
+/// - request: 语音翻译请求
+#[cfg(feature = "voice")]
+pub(crate) fn build_voice_request(
     config: &Config,
-    data: Vec<u8>,
-    name: &str,
-    ext: &str,
-) -> anyhow::Result<reqwest::blocking::multipart::Form> {
-    use reqwest::blocking::multipart;
+    request: &crate::voice::VoiceRequest,
+) -> anyhow::Result<(Vec<(&'static str, String)>, serde_json::Value)> {
+    use base64::Engine;
+    use hmac::{Hmac, Mac};
 
-    let mut kv = vec![
-        ("appid".to_string(), config.app_id.to_string()),
-        ("from".to_string(), config.from.to_string()),
-        ("to".to_string(), config.to.to_string()),
-        (
-            "timestamp".to_string(),
-            Local::now().timestamp().to_string(),
-        ),
-        ("type".to_string(), ext.to_string()),
+    if config.from == Lang::Auto || config.to == Lang::Auto {
+        anyhow::bail!("语音翻译需要指定源语言和目标语言");
+    }
+    request.validate()?;
+
+    let voice = request.encode();
+    let timestamp = Local::now().timestamp().to_string();
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(config.secret_key.as_bytes())?;
+    mac.update(config.app_id.as_bytes());
+    mac.update(timestamp.as_bytes());
+    mac.update(voice.as_bytes());
+    let sign = base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes());
+
+    let headers = vec![
+        ("X-Appid", config.app_id.clone()),
+        ("X-Timestamp", timestamp),
+        ("X-Sign", sign),
     ];
-    // 签名需要按key进行排序
-    kv.sort_by(|a, b| a.0.cmp(&b.0));
+    let body = serde_json::json!({
+        "from": config.from.to_string(),
+        "to": config.to.to_string(),
+        "voice": voice,
+        "format": request.format.to_string(),
+    });
+
+    Ok((headers, body))
+}
 
-    // 拼接查询参数,最后面必须要有&
-    let mut query = String::new();
-    for (k, v) in kv.iter() {
-        query.push_str(k);
-        query.push_str("=");
-        query.push_str(v);
-        query.push_str("&");
+/// 构建大模型翻译JSON请求体，签名方式与通用翻译相同
+/// - config: 客户端配置
+/// - q: 待翻译的文本
+/// - request: 大模型翻译请求参数
+#[cfg(feature = "llm")]
+pub(crate) fn build_llm_body(
+    config: &Config,
+    q: &str,
+    request: &crate::llm::LlmRequest,
+) -> serde_json::Value {
+    let salt = Local::now().timestamp().to_string();
+    let sign = md5_encode!(&config.app_id, q, &salt, &config.secret_key);
+
+    let mut body = serde_json::json!({
+        "appid": config.app_id,
+        "q": q,
+        "from": config.from.to_string(),
+        "to": config.to.to_string(),
+        "salt": salt,
+        "sign": sign,
+        "model_type": request.model_type.to_string(),
+    });
+    if let Some(reference) = &request.reference {
+        body["reference"] = reference.as_str().into();
     }
-
-    let sign = md5_encode!(&query, md5_encode!(&data), &config.secret_key);
-    // 创建表单
-    let mut params = multipart::Form::new();
-    for (k, v) in kv {
-        params = params.text(k, v);
+    if request.tag_handling {
+        body["tag_handling"] = "1".into();
+    }
+    if !request.ignore_tags.is_empty() {
+        body["ignore_tags"] = request.ignore_tags.join(",").into();
+    }
+    if request.need_intervene {
+        body["needIntervene"] = 1.into();
     }
-    params = params.text("sign", sign).part(
-        "file",
-        multipart::Part::bytes(data).file_name(name.to_string()),
-    );
 
-    Ok(params)
+    body
 }
 
-/// 构建文档翻译统计校验服务表单
-#[cfg(all(feature = "aio", feature = "doc"))]
-pub(crate) fn build_doc_count_form_aio(
-    config: &Config,
-    data: Vec<u8>,
-    name: &str,
-    ext: &str,
-) -> anyhow::Result<reqwest::multipart::Form> {
-    use reqwest::multipart;
-
+/// 构建文档翻译查询表单
+/// - config: 客户端配置
+/// - request_id: 文档翻译请求id
+#[cfg(feature = "doc")]
+pub(crate) fn build_doc_query_form(config: &Config, request_id: &str) -> HashMap<String, String> {
     let mut kv = vec![
         ("appid".to_string(), config.app_id.to_string()),
-        ("from".to_string(), config.from.to_string()),
-        ("to".to_string(), config.to.to_string()),
+        ("requestId".to_string(), request_id.to_string()),
         (
             "timestamp".to_string(),
             Local::now().timestamp().to_string(),
         ),
-        ("type".to_string(), ext.to_string()),
     ];
     // 签名需要按key进行排序
     kv.sort_by(|a, b| a.0.cmp(&b.0));
@@ -268,37 +487,44 @@ pub(crate) fn build_doc_count_form_aio(
     let mut query = String::new();
     for (k, v) in kv.iter() {
         query.push_str(k);
-        query.push_str("=");
+        query.push('=');
         query.push_str(v);
-        query.push_str("&");
+        query.push('&');
     }
 
-    let sign = md5_encode!(&query, md5_encode!(&data), &config.secret_key);
-    // 创建表单
-    let mut params = multipart::Form::new();
-    for (k, v) in kv {
-        params = params.text(k, v);
+    let sign = md5_encode!(&query, &config.secret_key);
+    let mut params: HashMap<String, String> = kv.into_iter().collect();
+    params.insert("sign".into(), sign);
+
+    params
+}
+
+/// 文档翻译回调通知的签名，按key排序拼接为`k=v&`后加上密钥计算md5
+/// - params: 除`sign`外的所有参数
+/// - secret_key: 密钥
+#[cfg(feature = "callback")]
+pub(crate) fn doc_callback_sign(
+    params: &std::collections::BTreeMap<String, String>,
+    secret_key: &str,
+) -> String {
+    let mut query = String::new();
+    for (k, v) in params.iter() {
+        query.push_str(k);
+        query.push('=');
+        query.push_str(v);
+        query.push('&');
     }
-    params = params.text("sign", sign).part(
-        "file",
-        multipart::Part::bytes(data).file_name(name.to_string()),
-    );
 
-    Ok(params)
+    md5_encode!(&query, secret_key)
 }
 
-/// 构建文档翻译服务表单
-#[cfg(all(feature = "blocking", feature = "doc"))]
-pub(crate) fn build_doc_form_blocking(
+/// 文档翻译统计校验服务的参数
+#[cfg(feature = "doc")]
+fn doc_count_params(
     config: &Config,
-    data: Vec<u8>,
-    name: &str,
-    typ: &str,
-    out_type: &str,
-) -> anyhow::Result<reqwest::blocking::multipart::Form> {
-    use reqwest::blocking::multipart;
-
-    let mut kv = vec![
+    format: crate::document::format::DocFormat,
+) -> Vec<(String, String)> {
+    vec![
         ("appid".to_string(), config.app_id.to_string()),
         ("from".to_string(), config.from.to_string()),
         ("to".to_string(), config.to.to_string()),
@@ -306,9 +532,41 @@ pub(crate) fn build_doc_form_blocking(
             "timestamp".to_string(),
             Local::now().timestamp().to_string(),
         ),
-        ("type".to_string(), typ.to_string()),
-        ("outPutType".to_string(), out_type.to_string()),
-    ];
+        ("type".to_string(), format.to_string()),
+    ]
+}
+
+/// 文档翻译服务的参数
+#[cfg(feature = "doc")]
+fn doc_params(
+    config: &Config,
+    format: crate::document::format::DocFormat,
+    out: crate::document::format::DocFormat,
+    file_id: Option<&str>,
+) -> Vec<(String, String)> {
+    let mut kv = doc_count_params(config, format);
+    kv.push(("outPutType".to_string(), out.to_string()));
+    // 统计接口返回的文件id，关联报价和翻译请求
+    if let Some(file_id) = file_id {
+        kv.push(("fileId".to_string(), file_id.to_string()));
+    }
+    if let Some(callback) = &config.doc_callback {
+        kv.push(("callbackUrl".to_string(), callback.to_string()));
+    }
+
+    kv
+}
+
+/// 对文档翻译参数签名，返回排序后的参数和签名
+/// - kv: 参数
+/// - file_md5: 文件数据的md5
+/// - secret_key: 密钥
+#[cfg(feature = "doc")]
+fn sign_doc_params(
+    mut kv: Vec<(String, String)>,
+    file_md5: &str,
+    secret_key: &str,
+) -> (Vec<(String, String)>, String) {
     // 签名需要按key进行排序
     kv.sort_by(|a, b| a.0.cmp(&b.0));
 
@@ -316,73 +574,103 @@ pub(crate) fn build_doc_form_blocking(
     let mut query = String::new();
     for (k, v) in kv.iter() {
         query.push_str(k);
-        query.push_str("=");
+        query.push('=');
         query.push_str(v);
-        query.push_str("&");
+        query.push('&');
     }
 
-    let sign = md5_encode!(&query, md5_encode!(&data), &config.secret_key);
+    let sign = md5_encode!(&query, file_md5, secret_key);
+    (kv, sign)
+}
 
-    // 创建表单
-    let mut params = multipart::Form::new();
-    for (k, v) in kv {
-        params = params.text(k, v);
-    }
+/// 构建文档翻译统计校验服务表单
+/// - part: 文件数据，需要设置文件名
+/// - file_md5: 文件数据的md5
+#[cfg(all(feature = "blocking", feature = "doc"))]
+pub(crate) fn build_doc_count_form_blocking(
+    config: &Config,
+    part: reqwest::blocking::multipart::Part,
+    file_md5: &str,
+    format: crate::document::format::DocFormat,
+) -> reqwest::blocking::multipart::Form {
+    let kv = doc_count_params(config, format);
+    doc_form_blocking(kv, part, file_md5, &config.secret_key)
+}
 
-    params = params.text("sign".to_string(), sign).part(
-        "file",
-        multipart::Part::bytes(data).file_name(name.to_string()),
-    );
+/// 构建文档翻译统计校验服务表单
+/// - part: 文件数据，需要设置文件名
+/// - file_md5: 文件数据的md5
+#[cfg(all(feature = "aio", feature = "doc"))]
+pub(crate) fn build_doc_count_form_aio(
+    config: &Config,
+    part: reqwest::multipart::Part,
+    file_md5: &str,
+    format: crate::document::format::DocFormat,
+) -> reqwest::multipart::Form {
+    let kv = doc_count_params(config, format);
+    doc_form_aio(kv, part, file_md5, &config.secret_key)
+}
 
-    Ok(params)
+/// 构建文档翻译服务表单
+/// - part: 文件数据，需要设置文件名
+/// - file_md5: 文件数据的md5
+#[cfg(all(feature = "blocking", feature = "doc"))]
+pub(crate) fn build_doc_form_blocking(
+    config: &Config,
+    part: reqwest::blocking::multipart::Part,
+    file_md5: &str,
+    format: crate::document::format::DocFormat,
+    out: crate::document::format::DocFormat,
+    file_id: Option<&str>,
+) -> reqwest::blocking::multipart::Form {
+    let kv = doc_params(config, format, out, file_id);
+    doc_form_blocking(kv, part, file_md5, &config.secret_key)
 }
 
 /// 构建文档翻译服务表单
+/// - part: 文件数据，需要设置文件名
+/// - file_md5: 文件数据的md5
 #[cfg(all(feature = "aio", feature = "doc"))]
 pub(crate) fn build_doc_form_aio(
     config: &Config,
-    data: Vec<u8>,
-    name: &str,
-    typ: &str,
-    out_type: &str,
-) -> anyhow::Result<reqwest::multipart::Form> {
-    use reqwest::multipart;
+    part: reqwest::multipart::Part,
+    file_md5: &str,
+    format: crate::document::format::DocFormat,
+    out: crate::document::format::DocFormat,
+    file_id: Option<&str>,
+) -> reqwest::multipart::Form {
+    let kv = doc_params(config, format, out, file_id);
+    doc_form_aio(kv, part, file_md5, &config.secret_key)
+}
 
-    let mut kv = vec![
-        ("appid".to_string(), config.app_id.to_string()),
-        ("from".to_string(), config.from.to_string()),
-        ("to".to_string(), config.to.to_string()),
-        (
-            "timestamp".to_string(),
-            Local::now().timestamp().to_string(),
-        ),
-        ("type".to_string(), typ.to_string()),
-        ("outPutType".to_string(), out_type.to_string()),
-    ];
-    // 签名需要按key进行排序
-    kv.sort_by(|a, b| a.0.cmp(&b.0));
+#[cfg(all(feature = "blocking", feature = "doc"))]
+fn doc_form_blocking(
+    kv: Vec<(String, String)>,
+    part: reqwest::blocking::multipart::Part,
+    file_md5: &str,
+    secret_key: &str,
+) -> reqwest::blocking::multipart::Form {
+    let (kv, sign) = sign_doc_params(kv, file_md5, secret_key);
 
-    // 拼接查询参数,最后面必须要有&
-    let mut query = String::new();
-    for (k, v) in kv.iter() {
-        query.push_str(k);
-        query.push_str("=");
-        query.push_str(v);
-        query.push_str("&");
+    let mut params = reqwest::blocking::multipart::Form::new();
+    for (k, v) in kv {
+        params = params.text(k, v);
     }
+    params.text("sign", sign).part("file", part)
+}
 
-    let sign = md5_encode!(&query, md5_encode!(&data), &config.secret_key);
+#[cfg(all(feature = "aio", feature = "doc"))]
+fn doc_form_aio(
+    kv: Vec<(String, String)>,
+    part: reqwest::multipart::Part,
+    file_md5: &str,
+    secret_key: &str,
+) -> reqwest::multipart::Form {
+    let (kv, sign) = sign_doc_params(kv, file_md5, secret_key);
 
-    // 创建表单
-    let mut params = multipart::Form::new();
+    let mut params = reqwest::multipart::Form::new();
     for (k, v) in kv {
         params = params.text(k, v);
     }
-
-    params = params.text("sign".to_string(), sign).part(
-        "file",
-        multipart::Part::bytes(data).file_name(name.to_string()),
-    );
-
-    Ok(params)
+    params.text("sign", sign).part("file", part)
 }
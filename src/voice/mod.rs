@@ -0,0 +1,211 @@
+//! 语音翻译相关模块
+//!
+//! 具体使用请查看: [https://fanyi-api.baidu.com/doc/26]
+//!
+//! 启动语音翻译需要开启`voice` feature
+//!
+//! ```toml
+//! baidu_trans = { version = "0.8.0", features = ["voice"] }
+//! ```
+//!
+//! 音频要求单声道、16bit采样，时长不超过60秒
+use std::{fmt::Display, path::Path, str::FromStr};
+
+pub mod model;
+
+/// 语音数据格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoiceFormat {
+    /// 不带文件头的原始PCM数据
+    Pcm,
+    /// WAV
+    Wav,
+    /// AMR
+    Amr,
+    /// M4A(AAC)
+    M4a,
+}
+
+impl VoiceFormat {
+    /// 支持的采样率
+    ///
+    /// - pcm、wav、m4a: 16000
+    /// - amr: 8000(AMR-NB)、16000(AMR-WB)
+    pub fn sample_rates(&self) -> &'static [u32] {
+        match self {
+            VoiceFormat::Amr => &[8000, 16000],
+            VoiceFormat::Pcm | VoiceFormat::Wav | VoiceFormat::M4a => &[16000],
+        }
+    }
+
+    /// 根据文件名的扩展名推断格式
+    pub fn from_name(name: impl AsRef<Path>) -> Option<VoiceFormat> {
+        name.as_ref().extension()?.to_str()?.parse().ok()
+    }
+}
+
+impl Display for VoiceFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = match self {
+            VoiceFormat::Pcm => "pcm",
+            VoiceFormat::Wav => "wav",
+            VoiceFormat::Amr => "amr",
+            VoiceFormat::M4a => "m4a",
+        };
+        write!(f, "{}", format)
+    }
+}
+
+impl FromStr for VoiceFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pcm" => Ok(VoiceFormat::Pcm),
+            "wav" => Ok(VoiceFormat::Wav),
+            "amr" => Ok(VoiceFormat::Amr),
+            "m4a" => Ok(VoiceFormat::M4a),
+            _ => Err(format!("暂不支持的语音格式: {}", s)),
+        }
+    }
+}
+
+/// 语音翻译请求
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceRequest {
+    /// 语音数据格式
+    pub format: VoiceFormat,
+    /// 采样率，默认16000
+    pub sample_rate: u32,
+    /// 语音数据
+    pub data: Vec<u8>,
+}
+
+impl VoiceRequest {
+    /// 最长语音时长(秒)
+    pub const MAX_SECONDS: u32 = 60;
+
+    /// 创建请求，采样率默认16000
+    ///
+    /// WAV格式会从文件头中读取采样率
+    pub fn new(format: VoiceFormat, data: Vec<u8>) -> Self {
+        let sample_rate = match format {
+            VoiceFormat::Wav => WavHeader::parse(&data).map_or(16000, |h| h.sample_rate),
+            _ => 16000,
+        };
+
+        Self {
+            format,
+            sample_rate,
+            data,
+        }
+    }
+
+    /// 设置采样率
+    pub fn sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    /// 检查采样率、声道数等是否符合接口要求
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if self.data.is_empty() {
+            anyhow::bail!("语音数据为空");
+        }
+        if !self.format.sample_rates().contains(&self.sample_rate) {
+            anyhow::bail!(
+                "{}格式不支持{}Hz采样率，支持的采样率: {:?}",
+                self.format,
+                self.sample_rate,
+                self.format.sample_rates()
+            );
+        }
+
+        let pcm_len = match self.format {
+            VoiceFormat::Pcm => self.data.len(),
+            VoiceFormat::Wav => {
+                let header = WavHeader::parse(&self.data)
+                    .ok_or_else(|| anyhow::anyhow!("无效的WAV文件头"))?;
+                if header.sample_rate != self.sample_rate {
+                    anyhow::bail!(
+                        "WAV文件头采样率为{}Hz，与请求的{}Hz不一致",
+                        header.sample_rate,
+                        self.sample_rate
+                    );
+                }
+                if header.channels != 1 || header.bits_per_sample != 16 {
+                    anyhow::bail!(
+                        "WAV需要单声道16bit采样，实际为{}声道{}bit",
+                        header.channels,
+                        header.bits_per_sample
+                    );
+                }
+                header.data_len
+            }
+            // 压缩格式无法在本地计算时长
+            VoiceFormat::Amr | VoiceFormat::M4a => return Ok(()),
+        };
+
+        let seconds = pcm_len as f64 / (self.sample_rate as f64 * 2.0);
+        if seconds > Self::MAX_SECONDS as f64 {
+            anyhow::bail!("语音时长{:.1}秒，超过{}秒限制", seconds, Self::MAX_SECONDS);
+        }
+
+        Ok(())
+    }
+
+    /// base64编码的语音数据
+    pub fn encode(&self) -> String {
+        use base64::Engine;
+
+        base64::engine::general_purpose::STANDARD.encode(&self.data)
+    }
+}
+
+/// WAV文件头中需要校验的字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WavHeader {
+    channels: u16,
+    sample_rate: u32,
+    bits_per_sample: u16,
+    data_len: usize,
+}
+
+impl WavHeader {
+    /// 解析RIFF/WAVE文件头，跳过`fmt `和`data`之外的块
+    fn parse(data: &[u8]) -> Option<WavHeader> {
+        if data.len() < 12 || &data[..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+            return None;
+        }
+
+        let u16_at = |i: usize| Some(u16::from_le_bytes(data.get(i..i + 2)?.try_into().ok()?));
+        let u32_at = |i: usize| Some(u32::from_le_bytes(data.get(i..i + 4)?.try_into().ok()?));
+
+        let mut format = None;
+        let mut pos = 12;
+        while pos + 8 <= data.len() {
+            let id = &data[pos..pos + 4];
+            let size = u32_at(pos + 4)? as usize;
+            let body = pos + 8;
+            match id {
+                b"fmt " => {
+                    format = Some((u16_at(body + 2)?, u32_at(body + 4)?, u16_at(body + 14)?))
+                }
+                b"data" => {
+                    let (channels, sample_rate, bits_per_sample) = format?;
+                    return Some(WavHeader {
+                        channels,
+                        sample_rate,
+                        bits_per_sample,
+                        data_len: size.min(data.len() - body),
+                    });
+                }
+                _ => {}
+            }
+            // 块大小为奇数时有一个填充字节
+            pos = body + size + (size & 1);
+        }
+
+        None
+    }
+}
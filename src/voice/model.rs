@@ -0,0 +1,54 @@
+//! 语音翻译返回相关结构
+
+use serde::Deserialize;
+
+use crate::model::ErrorCode;
+
+/// 语音翻译返回结果
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VoiceResult {
+    /// 错误码，0表示成功
+    pub code: ErrorCode,
+    /// 错误消息
+    #[serde(default)]
+    pub msg: String,
+    /// 结果数据
+    pub data: Option<VoiceData>,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 语音翻译结果数据
+#[derive(Debug, Clone, PartialEq, Default, Eq, Deserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VoiceData {
+    /// 识别出的原文
+    #[serde(default)]
+    pub source: String,
+    /// 译文
+    #[serde(default)]
+    pub target: String,
+    /// 译文发音，base64编码的mp3
+    #[serde(default)]
+    pub target_tts: String,
+    /// 未知字段，百度新增的字段会保留在这里
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl VoiceData {
+    /// 解码译文发音，没有发音时返回`Ok(None)`
+    pub fn tts(&self) -> anyhow::Result<Option<Vec<u8>>> {
+        use base64::Engine;
+
+        if self.target_tts.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(
+            base64::engine::general_purpose::STANDARD.decode(self.target_tts.trim())?,
+        ))
+    }
+}
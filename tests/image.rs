@@ -0,0 +1,339 @@
+#[cfg(feature = "image")]
+#[test]
+fn paste_image_decode() -> anyhow::Result<()> {
+    use baidu_trans::image::model::{Content, Data};
+
+    let data = Data {
+        paste_img: "aGVsbG8=".to_string(),
+        content: vec![Content {
+            paste_img: "data:image/jpeg;base64,d29ybGQ=".to_string(),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(data.paste_image()?, Some(b"hello".to_vec()));
+    assert_eq!(data.content[0].paste_image()?, Some(b"world".to_vec()));
+    assert_eq!(Data::default().paste_image()?, None);
+    Ok(())
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn paste_mode_param() {
+    use baidu_trans::image::{ImageRequest, PasteMode};
+
+    assert_eq!(ImageRequest::default().paste.to_string(), "0");
+    let request = ImageRequest::default().paste(PasteMode::PerBlock);
+    assert_eq!(request.paste.to_string(), "2");
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn rescale_geometry() {
+    use baidu_trans::image::model::{Content, Point};
+
+    let mut content = Content {
+        rect: "10 20 30 40".to_string(),
+        points: vec![Point { x: 10, y: 20 }, Point { x: 40, y: 60 }],
+        ..Default::default()
+    };
+    content.rescale(2.0);
+    assert_eq!(content.rect, "20 40 60 80");
+    assert_eq!(content.points[1], Point { x: 80, y: 120 });
+}
+
+#[cfg(feature = "preprocess")]
+#[test]
+fn preprocess_oversized_and_unsupported() -> anyhow::Result<()> {
+    use baidu_trans::image::preprocess::{prepare, PreprocessOptions};
+    use image::{ImageBuffer, ImageFormat, Rgb};
+    use std::io::Cursor;
+
+    let img = ImageBuffer::from_fn(5000, 100, |x, _| Rgb([(x % 256) as u8, 0, 0]));
+    let mut png = Cursor::new(Vec::new());
+    img.write_to(&mut png, ImageFormat::Png)?;
+
+    let prepared = prepare(png.get_ref(), &PreprocessOptions::default())?;
+    assert_eq!(prepared.format, ImageFormat::Png);
+    assert!((prepared.scale - 4096.0 / 5000.0).abs() < 1e-9);
+    assert_eq!(image::load_from_memory(&prepared.data)?.width(), 4096);
+
+    let mut bmp = Cursor::new(Vec::new());
+    ImageBuffer::from_pixel(64, 64, Rgb([255u8, 255, 255])).write_to(&mut bmp, ImageFormat::Bmp)?;
+    let prepared = prepare(bmp.get_ref(), &PreprocessOptions::default())?;
+    assert_eq!(prepared.format, ImageFormat::Jpeg);
+    assert_eq!(prepared.scale, 1.0);
+    assert_eq!(prepared.file_name("a.bmp"), "a.jpg");
+
+    // 体积限制小于任何可能的JPEG时返回错误而不是一直缩小
+    let options = PreprocessOptions {
+        max_bytes: 10,
+        min_side: 0,
+        ..Default::default()
+    };
+    assert!(prepare(bmp.get_ref(), &options).is_err());
+
+    // 初始质量低于50时依然会压缩
+    let noise = ImageBuffer::from_fn(256, 256, |x, y| {
+        Rgb([
+            ((x * 31) ^ (y * 17)) as u8,
+            (x * y) as u8,
+            (x + y * 7) as u8,
+        ])
+    });
+    let mut bmp = Cursor::new(Vec::new());
+    noise.write_to(&mut bmp, ImageFormat::Bmp)?;
+    let options = PreprocessOptions {
+        max_bytes: 64 * 1024,
+        jpeg_quality: 40,
+        ..Default::default()
+    };
+    let prepared = prepare(bmp.get_ref(), &options)?;
+    assert_eq!(prepared.format, ImageFormat::Jpeg);
+    assert!(prepared.data.len() <= options.max_bytes);
+
+    let options = PreprocessOptions {
+        jpeg_quality: 0,
+        ..Default::default()
+    };
+    assert!(prepare(bmp.get_ref(), &options).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn content_geometry() -> anyhow::Result<()> {
+    use baidu_trans::image::geometry::Rect;
+    use baidu_trans::image::model::{Content, Point};
+
+    let content = Content {
+        rect: "254 278 252 27".to_string(),
+        points: vec![
+            Point { x: 254, y: 280 },
+            Point { x: 506, y: 278 },
+            Point { x: 506, y: 303 },
+            Point { x: 254, y: 305 },
+        ],
+        ..Default::default()
+    };
+    let rect = content.rect()?;
+    assert_eq!(
+        rect,
+        Rect {
+            left: 254,
+            top: 278,
+            width: 252,
+            height: 27
+        }
+    );
+    assert_eq!(rect.to_string(), content.rect);
+    assert_eq!(content.bounding_box(), Some(rect));
+    assert!(content.angle() < 0.0 && content.angle() > -1.0);
+    let (cx, cy) = content.centroid().unwrap();
+    assert!((cx - 380.0).abs() < 0.5 && (cy - 291.5).abs() < 0.5);
+    assert!("1 2 3".parse::<Rect>().is_err());
+    Ok(())
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn reading_order() {
+    use baidu_trans::image::model::{Content, Data};
+
+    let block = |src: &str, rect: &str| Content {
+        src: src.to_string(),
+        rect: rect.to_string(),
+        ..Default::default()
+    };
+    let mut data = Data {
+        content: vec![
+            block("c", "10 60 50 20"),
+            block("b", "200 12 50 20"),
+            block("a", "10 10 50 20"),
+        ],
+        ..Default::default()
+    };
+    data.sort_reading_order();
+    let order: Vec<_> = data.content.iter().map(|c| c.src.as_str()).collect();
+    assert_eq!(order, ["a", "b", "c"]);
+}
+
+#[cfg(feature = "render")]
+#[test]
+fn render_translation() -> anyhow::Result<()> {
+    use baidu_trans::image::model::{Content, Data, ImageResult, Point};
+    use baidu_trans::image::render::Renderer;
+    use image::{DynamicImage, Rgba, RgbaImage};
+
+    // DejaVu Sans中可打印ASCII字符的子集
+    let font = std::fs::read("tests/font.ttf")?;
+
+    // 白底图片上有一块黑色的原文区域
+    let mut img = RgbaImage::from_pixel(200, 60, Rgba([255, 255, 255, 255]));
+    for x in 10..190 {
+        for y in 10..50 {
+            img.put_pixel(x, y, Rgba([0, 0, 0, 255]));
+        }
+    }
+    let result = ImageResult {
+        data: Some(Data {
+            content: vec![Content {
+                dst: "Hello World".to_string(),
+                rect: "10 10 180 40".to_string(),
+                points: vec![
+                    Point { x: 10, y: 10 },
+                    Point { x: 190, y: 10 },
+                    Point { x: 190, y: 50 },
+                    Point { x: 10, y: 50 },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let renderer = Renderer::new(font)?;
+    let out = renderer.render_image(&DynamicImage::ImageRgba8(img), &result);
+    assert_eq!(out.get_pixel(11, 11), &Rgba([255, 255, 255, 255]));
+    let dark = out.pixels().filter(|p| p.0[0] < 128).count();
+    assert!(dark > 50);
+
+    let empty = renderer.render_image(&DynamicImage::ImageRgba8(RgbaImage::new(0, 0)), &result);
+    assert_eq!(empty.dimensions(), (0, 0));
+    Ok(())
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn export_formats() {
+    use baidu_trans::image::export::{to_alto, to_coco, to_hocr, Page};
+    use baidu_trans::image::model::{Content, Data, ImageResult, Point};
+
+    let result = ImageResult {
+        data: Some(Data {
+            from: "en".to_string(),
+            to: "zh".to_string(),
+            content: vec![Content {
+                src: "Tom & Jerry".to_string(),
+                dst: "猫和老鼠".to_string(),
+                rect: "10 20 100 30".to_string(),
+                line_count: 1,
+                points: vec![
+                    Point { x: 10, y: 20 },
+                    Point { x: 110, y: 20 },
+                    Point { x: 110, y: 50 },
+                    Point { x: 10, y: 50 },
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let page = Page::new("a.png", 200, 100);
+
+    let hocr = to_hocr(&result, &page);
+    assert!(hocr.contains("bbox 0 0 200 100"));
+    assert!(hocr.contains("title=\"bbox 10 20 110 50; poly 10 20 110 20 110 50 10 50"));
+    assert!(hocr.contains("data-dst=\"猫和老鼠\">Tom &amp; Jerry</span>"));
+
+    let alto = to_alto(&result, &page);
+    assert!(alto.contains("<Polygon POINTS=\"10,20 110,20 110,50 10,50\"/>"));
+    assert!(alto.contains("CONTENT=\"Tom &amp; Jerry\""));
+    assert!(alto.contains("<ALTERNATIVE PURPOSE=\"translation:zh\">猫和老鼠</ALTERNATIVE>"));
+
+    let coco = to_coco(&result, &page);
+    assert_eq!(coco["images"][0]["width"], 200);
+    let annotation = &coco["annotations"][0];
+    assert_eq!(annotation["bbox"], serde_json::json!([10, 20, 100, 30]));
+    assert_eq!(annotation["segmentation"][0].as_array().unwrap().len(), 8);
+    assert_eq!(annotation["attributes"]["dst"], "猫和老鼠");
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn ocr_paragraphs() {
+    use baidu_trans::image::model::{Content, Data, ImageResult};
+    use baidu_trans::image::ocr::OcrResult;
+
+    let line = |src: &str, rect: &str, line_count| Content {
+        src: src.to_string(),
+        rect: rect.to_string(),
+        line_count,
+        ..Default::default()
+    };
+    let result = ImageResult {
+        data: Some(Data {
+            from: "en".to_string(),
+            sum_src: "all".to_string(),
+            content: vec![
+                line("Hello", "10 10 100 20", 1),
+                line("world", "10 34 80 20", 1),
+                line("Two lines merged", "10 100 200 40", 2),
+                line("Footer", "10 300 60 20", 1),
+            ],
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let ocr = OcrResult::from(result);
+    assert_eq!(ocr.lang, "en");
+    assert_eq!(ocr.segments.len(), 4);
+    assert_eq!(ocr.segments[1].rect.top, 34);
+
+    let paragraphs = ocr.paragraphs();
+    let texts: Vec<_> = paragraphs.iter().map(|p| p.text.as_str()).collect();
+    assert_eq!(texts, ["Hello world", "Two lines merged", "Footer"]);
+    assert_eq!(paragraphs[0].line_count, 2);
+    assert_eq!(paragraphs[0].rect.bottom(), 54);
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_value(&ocr).unwrap();
+        assert_eq!(json["lang"], "en");
+        assert_eq!(json["segments"][1]["rect"]["top"], 34);
+        assert_eq!(json["segments"][3]["text"], "Footer");
+    }
+}
+
+#[cfg(feature = "batch")]
+#[tokio::test]
+async fn batch_skip_completed() -> anyhow::Result<()> {
+    use baidu_trans::{
+        aio::Client,
+        config::Config,
+        image::batch::{result_path, BatchOptions, BatchStatus},
+    };
+
+    let dir = std::env::temp_dir().join("baidu_trans_batch_skip");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("sub"))?;
+    for name in ["a.png", "sub/b.JPG", "sub/b.JPG.paste.jpg", "c.txt"] {
+        std::fs::write(dir.join(name), b"")?;
+    }
+    for name in ["a.png", "sub/b.JPG"] {
+        std::fs::write(
+            result_path(&dir.join(name)),
+            r#"{"error_code":"0","error_msg":"success"}"#,
+        )?;
+    }
+
+    let client = Client::new(Config::new(String::new(), String::new()));
+    let mut seen = Vec::new();
+    let report = client
+        .image_translate_dir(&dir, &BatchOptions::default(), |progress| {
+            assert!(matches!(progress.status, BatchStatus::Skipped));
+            assert_eq!(progress.total, 2);
+            seen.push(progress.completed);
+        })
+        .await?;
+
+    assert_eq!(seen, vec![1, 2]);
+    assert_eq!(report.skipped.len(), 2);
+    assert!(report.translated.is_empty() && report.failed.is_empty());
+
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,549 @@
+use baidu_trans::model::CommonResult;
+
+#[test]
+fn dictionary_parse() -> anyhow::Result<()> {
+    let dict = r#"{"word_result":{"edict":{"item":[{"tr_group":[{"tr":["an expression of greeting"],"example":["every morning they exchanged polite hellos"],"similar_word":["hullo","hi","howdy"]}],"pos":"noun"}],"word":"hello"},"zdict":"","simple_means":{"word_name":"hello","from":"original","word_means":["喂","哈罗","你好"],"symbols":[{"ph_am":"həˈloʊ","ph_en":"həˈləʊ","parts":[{"part":"int.","means":["喂","哈罗"]},{"part":"n.","means":["引人注意的呼声"]}]}]}},"lang":"1"}"#;
+    let resp = CommonResult {
+        dict: Some(dict.to_string()),
+        ..Default::default()
+    };
+
+    let dict = resp.dictionary()?.unwrap();
+    assert_eq!(dict.ph_en(), Some("həˈləʊ"));
+    assert_eq!(dict.ph_am(), Some("həˈloʊ"));
+    assert_eq!(dict.parts().len(), 2);
+    assert_eq!(dict.parts()[0].part, "int.");
+    assert_eq!(dict.means(), ["喂", "哈罗", "你好"]);
+    assert_eq!(
+        dict.examples(),
+        ["every morning they exchanged polite hellos"]
+    );
+    assert_eq!(dict.synonyms(), ["hullo", "hi", "howdy"]);
+    Ok(())
+}
+
+#[test]
+fn dictionary_parse_zh() -> anyhow::Result<()> {
+    let dict = r#"{"word_result":{"edict":"","simple_means":{"word_name":"你好","symbols":[{"word_symbol":"nǐ hǎo","parts":[{"part_name":"","means":[{"text":"hello","word_mean":"hello"},{"text":"hi","word_mean":"hi"}]}]}]}},"lang":"0"}"#;
+    let resp = CommonResult {
+        dict: Some(dict.to_string()),
+        ..Default::default()
+    };
+
+    let dict = resp.dictionary()?.unwrap();
+    assert!(dict.word_result.edict.is_none());
+    assert_eq!(dict.ph_en(), None);
+    assert_eq!(dict.parts()[0].means, ["hello", "hi"]);
+    assert!(CommonResult::default().dictionary()?.is_none());
+    Ok(())
+}
+
+#[test]
+fn joined_text() {
+    use baidu_trans::model::TranslateResult;
+
+    let resp = CommonResult {
+        trans_result: Some(vec![
+            TranslateResult {
+                src: "Hello".to_string(),
+                dst: "你好".to_string(),
+                ..Default::default()
+            },
+            TranslateResult {
+                src: "World".to_string(),
+                dst: "世界".to_string(),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+    assert_eq!(resp.src_text(), "Hello\nWorld");
+    assert_eq!(resp.dst_text(), "你好\n世界");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn common_result_round_trip() -> anyhow::Result<()> {
+    let body = r#"{"from":"en","to":"zh","trans_result":[{"src":"apple","dst":"苹果"}],"src_tts":"https://fanyiapp.cdn.bcebos.com/zhdict/mp3/apple.mp3","dict":"{\"lang\":\"1\"}"}"#;
+    let resp: CommonResult = serde_json::from_str(body)?;
+
+    let json = serde_json::to_string(&resp)?;
+    let back: CommonResult = serde_json::from_str(&json)?;
+    assert_eq!(resp, back);
+
+    let dict = resp.dictionary()?.unwrap();
+    let back = serde_json::from_value(serde_json::to_value(&dict)?)?;
+    assert_eq!(dict, back);
+    Ok(())
+}
+
+#[test]
+fn error_code_int_or_string() -> anyhow::Result<()> {
+    use baidu_trans::model::ErrorCode;
+
+    let code: ErrorCode = serde_json::from_str("52000")?;
+    assert!(code.is_numeric());
+    assert!(code.is_success());
+    assert_eq!(code, 52000);
+
+    let code: ErrorCode = serde_json::from_str(r#""54003""#)?;
+    assert_eq!(code.code(), Some(54003));
+    assert!(!code.is_success());
+    assert_eq!(code, "54003");
+    assert_eq!(code, ErrorCode::from(54003));
+
+    let resp: CommonResult =
+        serde_json::from_str(r#"{"error_code":"52001","error_msg":"TIMEOUT"}"#)?;
+    assert_eq!(resp.error_code.unwrap().code(), Some(52001));
+    Ok(())
+}
+
+#[test]
+fn unknown_fields_preserved() -> anyhow::Result<()> {
+    let resp: CommonResult = serde_json::from_str(
+        r#"{"from":"en","to":"zh","trans_result":[{"src":"a","dst":"b","score":1}],"log_id":42}"#,
+    )?;
+    assert_eq!(resp.extra["log_id"], 42);
+    assert_eq!(resp.trans_result.unwrap()[0].extra["score"], 1);
+    Ok(())
+}
+
+#[cfg(feature = "domain")]
+#[test]
+fn domain_parse() {
+    use baidu_trans::domain::Domain;
+
+    for domain in [Domain::It, Domain::Senimed, Domain::Contract] {
+        assert_eq!(domain.to_string().parse::<Domain>(), Ok(domain.clone()));
+    }
+    assert_eq!(
+        "patent".parse::<Domain>(),
+        Ok(Domain::Other("patent".to_string()))
+    );
+    assert!("".parse::<Domain>().is_err());
+
+    let domain: Domain = serde_json::from_str(r#""law""#).unwrap();
+    assert_eq!(domain, Domain::Law);
+}
+
+#[cfg(feature = "domain")]
+#[test]
+fn domain_supports() {
+    use baidu_trans::{domain::Domain, lang::Lang};
+
+    assert_eq!(Domain::Electronics.supports(Lang::Zh, Lang::En), Some(true));
+    assert_eq!(
+        Domain::Electronics.supports(Lang::En, Lang::Zh),
+        Some(false)
+    );
+    assert_eq!(Domain::Law.supports(Lang::En, Lang::Zh), Some(true));
+    assert_eq!(Domain::Law.supports(Lang::Jp, Lang::Zh), Some(false));
+    assert_eq!(Domain::Law.supports(Lang::Auto, Lang::Zh), None);
+    assert_eq!(
+        Domain::Other("patent".to_string()).supports(Lang::Zh, Lang::En),
+        None
+    );
+}
+
+#[cfg(feature = "doc")]
+#[test]
+fn doc_query_status() {
+    use std::path::Path;
+
+    use baidu_trans::document::{
+        format::DocFormat,
+        job::output_path,
+        model::{DocQueryResult, DocStatus},
+    };
+    use baidu_trans::lang::Lang;
+
+    let result: DocQueryResult = serde_json::from_str(
+        r#"{"error_code":52000,"error_msg":"success","data":{"requestId":"1","status":"Succeeded","fileUrl":"https://example.com/a.docx"}}"#,
+    )
+    .unwrap();
+    let data = result.data.unwrap();
+    assert_eq!(data.status, DocStatus::Succeeded);
+    assert!(data.status.is_finished());
+    assert!(!DocStatus::Running.is_finished());
+    assert_eq!(
+        DocStatus::from("Paused"),
+        DocStatus::Other("Paused".to_string())
+    );
+    assert!(!DocStatus::from("Paused").is_finished());
+
+    assert_eq!(
+        output_path(Path::new("docs/report.docx"), Lang::En, DocFormat::Pdf).unwrap(),
+        Path::new("docs/report.en.pdf")
+    );
+    assert!(output_path(Path::new("docs/report.docx"), Lang::Auto, DocFormat::Pdf).is_err());
+}
+
+#[cfg(feature = "doc")]
+#[test]
+fn doc_format_infer() {
+    use baidu_trans::document::format::DocFormat;
+
+    assert_eq!(DocFormat::from_name("a/Report.DOCX"), Some(DocFormat::Docx));
+    assert_eq!(DocFormat::from_name("index.htm"), Some(DocFormat::Html));
+    assert_eq!(DocFormat::from_name("a.md"), None);
+    assert!("doc x".parse::<DocFormat>().is_err());
+
+    assert_eq!(
+        DocFormat::infer("a.docx", b"%PDF-1.7\n"),
+        Some(DocFormat::Pdf)
+    );
+    assert_eq!(
+        DocFormat::infer("a.bin", b"PK\x03\x04....xl/workbook.xml"),
+        Some(DocFormat::Xlsx)
+    );
+    assert_eq!(
+        DocFormat::infer("a.wps", &[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]),
+        Some(DocFormat::Wps)
+    );
+    assert_eq!(
+        DocFormat::infer("page", b"\xef\xbb\xbf<!DOCTYPE html><html>"),
+        Some(DocFormat::Html)
+    );
+
+    assert!(DocFormat::Pdf.can_convert_to(DocFormat::Docx));
+    assert!(DocFormat::Xls.check_conversion(DocFormat::Pdf).is_err());
+}
+
+#[cfg(feature = "doc")]
+#[test]
+fn doc_quote_approval() {
+    use baidu_trans::document::{
+        model::DocCountData,
+        quote::{max_amount, QuoteRejected},
+    };
+
+    let quote = DocCountData {
+        char_count: 12000,
+        amount: 1250,
+        ..Default::default()
+    };
+    assert!(max_amount(2000)(&quote));
+    assert!(!max_amount(1000)(&quote));
+
+    let err: anyhow::Error = QuoteRejected { quote }.into();
+    assert_eq!(
+        err.to_string(),
+        "文档翻译报价未通过确认: 12000字符，12.50元"
+    );
+    assert_eq!(
+        err.downcast_ref::<QuoteRejected>().unwrap().quote.amount,
+        1250
+    );
+}
+
+#[cfg(feature = "callback")]
+#[test]
+fn doc_callback_verify() {
+    use baidu_trans::document::{
+        callback::{CallbackError, CallbackHandler},
+        model::DocStatus,
+    };
+    use md5::{Digest, Md5};
+
+    let query = "appid=123&fileUrl=https://example.com/a.docx&requestId=42&status=Succeeded&";
+    let sign = format!(
+        "{:x}",
+        Md5::new()
+            .chain_update(query)
+            .chain_update("secret")
+            .finalize()
+    );
+    let handler = CallbackHandler::new("123", "secret");
+
+    let body = format!(
+        "appid=123&requestId=42&status=Succeeded&fileUrl=https%3A%2F%2Fexample.com%2Fa.docx&sign={}",
+        sign
+    );
+    let notification = handler
+        .handle(Some("application/x-www-form-urlencoded"), body.as_bytes())
+        .unwrap();
+    assert_eq!(notification.request_id, "42");
+    assert_eq!(notification.status, DocStatus::Succeeded);
+    assert_eq!(notification.file_url, "https://example.com/a.docx");
+
+    let body = format!(
+        r#"{{"appid":"123","requestId":42,"status":"Succeeded","fileUrl":"https://example.com/a.docx","sign":"{}"}}"#,
+        sign
+    );
+    assert!(handler.handle(None, body.as_bytes()).is_ok());
+
+    let tampered = body.replace("Succeeded", "Failed");
+    assert_eq!(
+        handler.handle(None, tampered.as_bytes()),
+        Err(CallbackError::InvalidSign)
+    );
+    assert_eq!(
+        handler.handle(None, br#"{"requestId":"42"}"#),
+        Err(CallbackError::MissingSign)
+    );
+}
+
+#[cfg(all(feature = "batch", feature = "doc"))]
+#[tokio::test]
+async fn doc_batch_resume() -> anyhow::Result<()> {
+    use std::path::Path;
+
+    use baidu_trans::{
+        aio::Client,
+        config::Config,
+        document::{
+            batch::{
+                manifest_path, mirror_path, DocBatchOptions, JobState, Manifest, ManifestEntry,
+            },
+            format::DocFormat,
+        },
+        lang::Lang,
+    };
+
+    let root = std::env::temp_dir().join("baidu_trans_doc_batch");
+    let _ = std::fs::remove_dir_all(&root);
+    // 输出目录在输入目录中，已有的译文不能被当成新文档
+    let (dir, out_dir) = (root.join("in"), root.join("in/out"));
+    std::fs::create_dir_all(dir.join("sub"))?;
+    std::fs::create_dir_all(out_dir.join("sub"))?;
+    std::fs::write(dir.join("sub/a.docx"), b"PK\x03\x04word/")?;
+    std::fs::write(out_dir.join("sub/a.docx.docx"), b"PK\x03\x04word/")?;
+    // 扩展名与文件头不一致，提交时无法转换格式，只有这个文档失败
+    std::fs::write(dir.join("b.xlsx"), b"%PDF-1.4")?;
+
+    let entry = |key: &str, format: &str, state| -> anyhow::Result<(String, ManifestEntry)> {
+        let metadata = std::fs::metadata(dir.join(key))?;
+        let modified = metadata
+            .modified()?
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_secs();
+        let entry = ManifestEntry {
+            format: format.to_string(),
+            out: format.to_string(),
+            size: metadata.len(),
+            modified,
+            char_count: 100,
+            amount: 150,
+            state,
+            ..Default::default()
+        };
+        Ok((key.to_string(), entry))
+    };
+    let mut manifest = Manifest {
+        from: "auto".to_string(),
+        to: "en".to_string(),
+        ..Default::default()
+    };
+    manifest.entries.extend([
+        entry("sub/a.docx", "docx", JobState::Downloaded)?,
+        entry("b.xlsx", "xlsx", JobState::Quoted)?,
+    ]);
+    manifest.save(&manifest_path(&out_dir)).await?;
+    assert_eq!(Manifest::load(&manifest_path(&out_dir)).await?, manifest);
+
+    let client = Client::new(Config::new(String::new(), String::new()));
+    client.lang(Lang::Auto, Lang::En);
+    let report = client
+        .doc_translate_dir(
+            &dir,
+            &out_dir,
+            &DocBatchOptions::default(),
+            |key, _| key == "b.xlsx",
+            |key, state| {
+                assert_eq!(key, "b.xlsx", "已下载的文档状态不变");
+                assert!(matches!(state, JobState::Failed(_)));
+            },
+        )
+        .await?;
+    assert!(report.downloaded.is_empty());
+    assert_eq!(report.failed.len(), 1);
+    assert!(report.failed[0].1.contains("不能输出为xlsx"));
+    assert_eq!(report.quote.to_string(), "1个文档，100字符，1.50元");
+    let manifest = Manifest::load(&manifest_path(&out_dir)).await?;
+    assert!(matches!(
+        manifest.entries["b.xlsx"].state,
+        JobState::Failed(_)
+    ));
+    assert_eq!(manifest.entries["sub/a.docx"].state, JobState::Downloaded);
+    let json = serde_json::to_value(&report)?;
+    assert_eq!(json["quote"]["amount"], 150);
+    assert_eq!(json["downloaded"], serde_json::json!([]));
+
+    assert_eq!(
+        mirror_path(Path::new("out"), "sub/a.doc", DocFormat::Docx),
+        Path::new("out/sub/a.doc.docx")
+    );
+
+    std::fs::remove_dir_all(&root)?;
+    Ok(())
+}
+
+#[cfg(feature = "voice")]
+#[test]
+fn voice_request_validate() {
+    use baidu_trans::voice::{model::VoiceData, VoiceFormat, VoiceRequest};
+
+    fn wav(sample_rate: u32, channels: u16, samples: usize) -> Vec<u8> {
+        let data_len = (samples * 2 * channels as usize) as u32;
+        let mut out = Vec::new();
+        out.extend_from_slice(b"RIFF");
+        out.extend_from_slice(&(36 + data_len).to_le_bytes());
+        out.extend_from_slice(b"WAVEfmt ");
+        out.extend_from_slice(&16u32.to_le_bytes());
+        out.extend_from_slice(&1u16.to_le_bytes());
+        out.extend_from_slice(&channels.to_le_bytes());
+        out.extend_from_slice(&sample_rate.to_le_bytes());
+        out.extend_from_slice(&(sample_rate * 2 * channels as u32).to_le_bytes());
+        out.extend_from_slice(&(2 * channels).to_le_bytes());
+        out.extend_from_slice(&16u16.to_le_bytes());
+        out.extend_from_slice(b"data");
+        out.extend_from_slice(&data_len.to_le_bytes());
+        out.resize(out.len() + data_len as usize, 0);
+        out
+    }
+
+    assert!(VoiceRequest::new(VoiceFormat::Wav, wav(16000, 1, 1600))
+        .validate()
+        .is_ok());
+    let request = VoiceRequest::new(VoiceFormat::Wav, wav(44100, 1, 100));
+    assert_eq!(request.sample_rate, 44100);
+    assert!(request.validate().is_err());
+    assert!(VoiceRequest::new(VoiceFormat::Wav, wav(16000, 2, 100))
+        .validate()
+        .is_err());
+
+    assert!(VoiceRequest::new(VoiceFormat::Pcm, vec![0; 16000 * 2 * 61])
+        .validate()
+        .is_err());
+    assert!(VoiceRequest::new(VoiceFormat::Amr, vec![0; 10])
+        .sample_rate(8000)
+        .validate()
+        .is_ok());
+    assert!(VoiceRequest::new(VoiceFormat::M4a, vec![0; 10])
+        .sample_rate(8000)
+        .validate()
+        .is_err());
+
+    assert_eq!(VoiceFormat::from_name("a.WAV"), Some(VoiceFormat::Wav));
+    assert_eq!(
+        VoiceRequest::new(VoiceFormat::Pcm, b"hi".to_vec()).encode(),
+        "aGk="
+    );
+
+    let data = VoiceData {
+        target_tts: "aGVsbG8=".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(data.tts().unwrap(), Some(b"hello".to_vec()));
+}
+
+#[cfg(feature = "bce")]
+#[test]
+fn bce_result_convert() -> anyhow::Result<()> {
+    use baidu_trans::bce::{model::BceResult, model::TokenResult, AccessToken};
+    use baidu_trans::model::CommonResult;
+
+    let ok: BceResult = serde_json::from_str(
+        r#"{"result":{"from":"en","to":"zh","trans_result":[{"src":"hello","dst":"你好"}]},"log_id":1}"#,
+    )?;
+    assert!(!ok.is_token_invalid());
+    let common = CommonResult::from(ok);
+    assert_eq!(common.error_code, None);
+    assert_eq!(common.trans_result.unwrap()[0].dst, "你好");
+
+    let expired: BceResult =
+        serde_json::from_str(r#"{"error_code":110,"error_msg":"Access token invalid"}"#)?;
+    assert!(expired.is_token_invalid());
+    assert!(CommonResult::from(expired).error_code.is_some());
+
+    let token: TokenResult = serde_json::from_str(r#"{"access_token":"t","expires_in":2592000}"#)?;
+    assert!(!AccessToken::try_from(token)?.is_expiring());
+    let token: TokenResult = serde_json::from_str(r#"{"access_token":"t","expires_in":60}"#)?;
+    assert!(AccessToken::try_from(token)?.is_expiring());
+    let token: TokenResult = serde_json::from_str(
+        r#"{"error":"invalid_client","error_description":"unknown client id"}"#,
+    )?;
+    assert!(AccessToken::try_from(token).is_err());
+    Ok(())
+}
+
+#[cfg(feature = "llm")]
+#[test]
+fn llm_result_convert() -> anyhow::Result<()> {
+    use baidu_trans::llm::{model::LlmResult, LlmRequest, ModelType};
+    use baidu_trans::model::CommonResult;
+
+    let request = LlmRequest::default()
+        .reference("营销文案，语气活泼")
+        .ignore_tags(["code"]);
+    assert_eq!(request.model_type, ModelType::Llm);
+    assert!(request.tag_handling);
+    assert_eq!("nmt".parse::<ModelType>()?, ModelType::Nmt);
+
+    let result: LlmResult = serde_json::from_str(
+        r#"{"from":"en","to":"zh","trans_result":[{"src":"Hi","dst":"嗨"},{"src":"Bye","dst":"再见"}]}"#,
+    )?;
+    assert_eq!(result.dst_text(), "嗨\n再见");
+    let common = CommonResult::from(result);
+    assert_eq!(common.error_code, None);
+    assert_eq!(common.trans_result.unwrap().len(), 2);
+
+    let failed: LlmResult =
+        serde_json::from_str(r#"{"error_code":"52003","error_msg":"UNAUTHORIZED USER"}"#)?;
+    assert_eq!(failed.dst_text(), "");
+    Ok(())
+}
+
+#[cfg(feature = "glossary")]
+#[test]
+fn glossary_protect_restore() -> anyhow::Result<()> {
+    use baidu_trans::glossary::Glossary;
+    use baidu_trans::lang::Lang;
+    use baidu_trans::model::{CommonResult, TranslateResult};
+
+    let csv = "source,target\n# 注释\nRust,Rust\nborrow checker,借用检查器\n";
+    let mut glossary = Glossary::from_csv(csv.as_bytes(), Lang::En, Lang::Zh)?;
+    let tbx = r#"<?xml version="1.0"?>
+<martif type="TBX"><text><body>
+  <termEntry>
+    <langSet xml:lang="en"><tig><term>crate</term></tig></langSet>
+    <langSet xml:lang="zh-CN"><tig><term>包</term></tig></langSet>
+    <langSet xml:lang="ja"><tig><term>クレート</term></tig></langSet>
+  </termEntry>
+</body></text></martif>"#;
+    glossary.extend(Glossary::from_tbx(tbx)?);
+    assert_eq!(glossary.len(), 8);
+
+    let protected = glossary.protect(
+        "The borrow checker in Rust checks every crate, not crates.",
+        Lang::Auto,
+        Lang::Zh,
+    );
+    assert_eq!(
+        protected.text,
+        "The {T0} in {T1} checks every {T2}, not crates."
+    );
+
+    let restored = protected.restore("{T1}中的{ T0 }检查每个｛t2｝");
+    assert_eq!(restored.text, "Rust中的借用检查器检查每个包");
+    assert!(restored.mangled.is_empty());
+
+    let result = CommonResult {
+        trans_result: Some(vec![TranslateResult {
+            src: protected.text.clone(),
+            dst: "{T1}中的借用检查检查每个{T2}".to_string(),
+            extra: Default::default(),
+        }]),
+        ..Default::default()
+    };
+    let result = protected.restore_result(result);
+    let item = &result.result.trans_result.unwrap()[0];
+    assert_eq!(
+        item.src,
+        "The borrow checker in Rust checks every crate, not crates."
+    );
+    assert_eq!(item.dst, "Rust中的借用检查检查每个包");
+    assert_eq!(result.mangled.len(), 1);
+    assert_eq!(result.mangled[0].source, "borrow checker");
+    Ok(())
+}
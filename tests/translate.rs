@@ -52,6 +52,30 @@ fn image_translate_blocking() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(all(feature = "blocking", feature = "image"))]
+#[test]
+fn image_translate_file_blocking() -> anyhow::Result<()> {
+    use baidu_trans::blocking::Client;
+    use baidu_trans::config::Config;
+    use baidu_trans::image::ImageRequest;
+    use baidu_trans::lang::Lang;
+
+    dotenv::dotenv()?;
+    let app_id = dotenv::var("APP_ID")?;
+    let app_secret = dotenv::var("APP_SECRET")?;
+
+    let client = Client::new(Config::new(app_id, app_secret));
+    client.lang(Lang::Auto, Lang::Zh);
+    client.image_request(ImageRequest::default().cuid("APICUID").mac("mac"));
+
+    let resp = client.image_translate_file("tests/a.png")?;
+    assert_eq!(resp.error_code, "0");
+
+    let resp = client.image_translate_reader("b.png", std::fs::File::open("tests/b.png")?)?;
+    assert_eq!(resp.error_code, "0");
+    Ok(())
+}
+
 #[cfg(feature = "aio")]
 #[tokio::test]
 async fn common_translate_aio() -> anyhow::Result<()> {
@@ -97,6 +121,29 @@ async fn image_translate_aio() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[cfg(all(feature = "aio", feature = "image"))]
+#[tokio::test]
+async fn image_translate_file_aio() -> anyhow::Result<()> {
+    use baidu_trans::aio::Client;
+    use baidu_trans::config::Config;
+    use baidu_trans::lang::Lang;
+
+    dotenv::dotenv()?;
+    let app_id = dotenv::var("APP_ID")?;
+    let app_secret = dotenv::var("APP_SECRET")?;
+
+    let client = Client::new(Config::new(app_id, app_secret));
+    client.lang(Lang::Auto, Lang::Zh);
+
+    let resp = client.image_translate_file("tests/a.png").await?;
+    assert_eq!(resp.error_code, "0");
+
+    let file = tokio::fs::File::open("tests/b.png").await?;
+    let resp = client.image_translate_reader("b.png", file).await?;
+    assert_eq!(resp.error_code, "0");
+    Ok(())
+}
+
 #[cfg(all(feature = "blocking", feature = "domain"))]
 #[test]
 fn domain_translate_blocking() -> anyhow::Result<()> {
@@ -162,6 +209,7 @@ fn doc_count_translate_blocking() -> anyhow::Result<()> {
 
     use baidu_trans::blocking::Client;
     use baidu_trans::config::Config;
+    use baidu_trans::document::format::DocFormat;
     use baidu_trans::lang::Lang;
 
     dotenv::dotenv()?;
@@ -173,7 +221,7 @@ fn doc_count_translate_blocking() -> anyhow::Result<()> {
     client.lang(Lang::Auto, Lang::Zh);
 
     let data = fs::read("tests/a.txt")?;
-    let resp = client.doc_count_translate(data, "a.txt", "txt")?;
+    let resp = client.doc_count_translate(data, "a.txt", DocFormat::Txt)?;
     assert_eq!(resp.error_code, 52000);
     dbg!(resp);
     Ok(())
@@ -186,6 +234,7 @@ async fn doc_count_translate_aio() -> anyhow::Result<()> {
 
     use baidu_trans::aio::Client;
     use baidu_trans::config::Config;
+    use baidu_trans::document::format::DocFormat;
     use baidu_trans::lang::Lang;
 
     dotenv::dotenv()?;
@@ -197,11 +246,15 @@ async fn doc_count_translate_aio() -> anyhow::Result<()> {
     client.lang(Lang::Auto, Lang::Zh);
 
     let data = fs::read("tests/a.txt")?;
-    let resp = client.doc_count_translate(data, "a.txt", "txt").await?;
+    let resp = client
+        .doc_count_translate(data, "a.txt", DocFormat::Txt)
+        .await?;
     assert_eq!(resp.error_code, 52000);
 
     let data = fs::read("tests/b.txt")?;
-    let resp = client.doc_count_translate(data, "a.txt", "txt").await?;
+    let resp = client
+        .doc_count_translate(data, "a.txt", DocFormat::Txt)
+        .await?;
     assert_eq!(resp.error_code, "70205");
     Ok(())
 }
@@ -211,6 +264,7 @@ async fn doc_count_translate_aio() -> anyhow::Result<()> {
 fn doc_translate_blocking() -> anyhow::Result<()> {
     use baidu_trans::blocking::Client;
     use baidu_trans::config::Config;
+    use baidu_trans::document::format::DocFormat;
     use baidu_trans::lang::Lang;
     use std::fs;
 
@@ -223,12 +277,12 @@ fn doc_translate_blocking() -> anyhow::Result<()> {
     client.lang(Lang::Auto, Lang::Zh);
 
     let data = fs::read("tests/a.txt")?;
-    let resp = client.doc_translate(data, "a.txt", "txt", "txt")?;
+    let resp = client.doc_translate(data, "a.txt", DocFormat::Txt, DocFormat::Txt)?;
     assert_eq!(resp.error_code, 52000);
     dbg!(resp);
 
     let data = fs::read("tests/b.txt")?;
-    let resp = client.doc_translate(data, "b.txt", "txt", "txt")?;
+    let resp = client.doc_translate(data, "b.txt", DocFormat::Txt, DocFormat::Txt)?;
     assert_eq!(resp.error_code, 52000);
     dbg!(resp);
     Ok(())
@@ -239,6 +293,7 @@ fn doc_translate_blocking() -> anyhow::Result<()> {
 async fn doc_translate_aio() -> anyhow::Result<()> {
     use baidu_trans::aio::Client;
     use baidu_trans::config::Config;
+    use baidu_trans::document::format::DocFormat;
     use baidu_trans::lang::Lang;
     use tokio::fs;
 
@@ -251,13 +306,99 @@ async fn doc_translate_aio() -> anyhow::Result<()> {
     client.lang(Lang::Auto, Lang::Zh);
 
     let data = fs::read("tests/a.txt").await?;
-    let resp = client.doc_translate(data, "a.txt", "txt", "txt").await?;
+    let resp = client
+        .doc_translate(data, "a.txt", DocFormat::Txt, DocFormat::Txt)
+        .await?;
     assert_eq!(resp.error_code, 52000);
     dbg!(resp);
 
     let data = fs::read("tests/b.txt").await?;
-    let resp = client.doc_translate(data, "b.txt", "txt", "txt").await?;
+    let resp = client
+        .doc_translate(data, "b.txt", DocFormat::Txt, DocFormat::Txt)
+        .await?;
     assert_eq!(resp.error_code, 52000);
     dbg!(resp);
     Ok(())
 }
+
+#[cfg(all(feature = "blocking", feature = "doc"))]
+#[test]
+fn doc_count_translate_file_blocking() -> anyhow::Result<()> {
+    use baidu_trans::blocking::Client;
+    use baidu_trans::config::Config;
+    use baidu_trans::lang::Lang;
+
+    dotenv::dotenv()?;
+    let app_id = dotenv::var("APP_ID")?;
+    let app_secret = dotenv::var("APP_SECRET")?;
+
+    let client = Client::new(Config::new(app_id, app_secret));
+    client.lang(Lang::Auto, Lang::Zh);
+
+    let resp = client.doc_count_translate_file("tests/a.txt")?;
+    assert_eq!(resp.error_code, 52000);
+    Ok(())
+}
+
+#[cfg(all(feature = "aio", feature = "doc"))]
+#[tokio::test]
+async fn doc_count_translate_file_aio() -> anyhow::Result<()> {
+    use baidu_trans::aio::Client;
+    use baidu_trans::config::Config;
+    use baidu_trans::lang::Lang;
+
+    dotenv::dotenv()?;
+    let app_id = dotenv::var("APP_ID")?;
+    let app_secret = dotenv::var("APP_SECRET")?;
+
+    let client = Client::new(Config::new(app_id, app_secret));
+    client.lang(Lang::Auto, Lang::Zh);
+
+    let resp = client.doc_count_translate_file("tests/a.txt").await?;
+    assert_eq!(resp.error_code, 52000);
+    Ok(())
+}
+
+#[cfg(all(feature = "blocking", feature = "llm"))]
+#[test]
+fn llm_translate_blocking() -> anyhow::Result<()> {
+    use baidu_trans::blocking::Client;
+    use baidu_trans::config::Config;
+    use baidu_trans::lang::Lang;
+    use baidu_trans::llm::LlmRequest;
+
+    dotenv::dotenv()?;
+    let app_id = dotenv::var("APP_ID")?;
+    let app_secret = dotenv::var("APP_SECRET")?;
+
+    let client = Client::new(Config::new(app_id, app_secret));
+    client.lang(Lang::En, Lang::Zh);
+
+    let request = LlmRequest::default().reference("Marketing copy, keep it lively.");
+    let resp = client.llm_translate("Fresh deals every day!", &request)?;
+    assert_eq!(resp.error_code, None);
+    Ok(())
+}
+
+#[cfg(all(feature = "aio", feature = "llm"))]
+#[tokio::test]
+async fn llm_translate_aio() -> anyhow::Result<()> {
+    use baidu_trans::aio::Client;
+    use baidu_trans::config::Config;
+    use baidu_trans::lang::Lang;
+    use baidu_trans::llm::LlmRequest;
+
+    dotenv::dotenv()?;
+    let app_id = dotenv::var("APP_ID")?;
+    let app_secret = dotenv::var("APP_SECRET")?;
+
+    let client = Client::new(Config::new(app_id, app_secret));
+    client.lang(Lang::En, Lang::Zh);
+
+    let request = LlmRequest::default().reference("Marketing copy, keep it lively.");
+    let resp = client
+        .llm_translate("Fresh deals every day!", &request)
+        .await?;
+    assert_eq!(resp.error_code, None);
+    Ok(())
+}